@@ -0,0 +1,240 @@
+use crate::interpolate;
+
+/// Maps a perceived brightness from 0.0 to 100.0 to the output of linear LEDs or PWM dimmers
+#[derive(Debug, Clone, Copy)]
+pub enum BrightnessCurve<'lookup> {
+    /// Output is proportional to the brightness
+    Linear,
+    /// Output is `brightness ^ gamma`, commonly 2.2 to 2.8
+    Gamma(f32),
+    /// Brightness is the CIE 1976 L* lightness
+    Cie1976,
+    /// Output from 0.0 to 1.0 for brightness values evenly spaced from 0.0 to 100.0.
+    ///
+    /// Values between the entries are interpolated linearly.
+    /// Needs at least two entries which are increasing.
+    /// Outputs outside of 0.0 to 1.0 are clamped.
+    Lookup(&'lookup [f32]),
+}
+
+/// Resolution of a PWM output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PwmResolution {
+    Bits8,
+    Bits10,
+    Bits12,
+    Bits16,
+}
+
+impl PwmResolution {
+    #[must_use]
+    pub const fn max_duty(self) -> u16 {
+        match self {
+            Self::Bits8 => 0xff,
+            Self::Bits10 => 0x3ff,
+            Self::Bits12 => 0xfff,
+            Self::Bits16 => 0xffff,
+        }
+    }
+}
+
+impl BrightnessCurve<'_> {
+    /// Relative output from 0.0 to 1.0 for the brightness from 0.0 to 100.0.
+    ///
+    /// The output is clamped so that a negative gamma or lookup entries outside of 0.0 to 1.0 stay within.
+    #[must_use]
+    pub fn output(&self, brightness: f32) -> f32 {
+        let brightness = sanitize(brightness, 100.0);
+        let output = match self {
+            Self::Linear => brightness / 100.0,
+            Self::Gamma(gamma) => libm::powf(brightness / 100.0, *gamma),
+            Self::Cie1976 => {
+                if brightness > 8.0 {
+                    let cube_root = (brightness + 16.0) / 116.0;
+                    cube_root * cube_root * cube_root
+                } else {
+                    brightness / 903.3
+                }
+            }
+            Self::Lookup(table) => {
+                let Some(last) = table.len().checked_sub(1).filter(|last| *last > 0) else {
+                    return brightness / 100.0;
+                };
+                let (index, position) = segment(brightness / 100.0, last);
+                interpolate::f32(table[index], table[index + 1], position)
+            }
+        };
+        sanitize(output, 1.0)
+    }
+
+    /// Inverse of [`BrightnessCurve::output`]
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn brightness(&self, output: f32) -> f32 {
+        let output = sanitize(output, 1.0);
+        match self {
+            Self::Linear => output * 100.0,
+            Self::Gamma(gamma) => libm::powf(output, 1.0 / gamma) * 100.0,
+            Self::Cie1976 => {
+                if output > 0.008_856 {
+                    116.0 * libm::cbrtf(output) - 16.0
+                } else {
+                    output * 903.3
+                }
+            }
+            Self::Lookup(table) => {
+                let Some(last) = table.len().checked_sub(1).filter(|last| *last > 0) else {
+                    return output * 100.0;
+                };
+                let index = table
+                    .windows(2)
+                    .position(|pair| output <= pair[1])
+                    .unwrap_or(last - 1);
+                let lower = table[index];
+                let upper = table[index + 1];
+                let position = if upper > lower {
+                    ((output - lower) / (upper - lower)).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                #[expect(clippy::cast_precision_loss)]
+                let brightness = (index as f32 + position) / last as f32;
+                brightness * 100.0
+            }
+        }
+    }
+
+    /// Duty cycle for the brightness from 0.0 to 100.0 rounded to the nearest value
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn duty(&self, brightness: f32, resolution: PwmResolution) -> u16 {
+        let max = f32::from(resolution.max_duty());
+        (self.output(brightness) * max + 0.5) as u16
+    }
+
+    /// Brightness from 0.0 to 100.0 of the duty cycle
+    #[must_use]
+    pub fn brightness_from_duty(&self, duty: u16, resolution: PwmResolution) -> f32 {
+        let max = f32::from(resolution.max_duty());
+        self.brightness(f32::from(duty) / max)
+    }
+}
+
+/// Clamps to 0.0..=max and treats NaN as 0.0
+const fn sanitize(value: f32, max: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, max)
+    }
+}
+
+/// Index of the lookup segment and the position within it for a position from 0.0 to 1.0
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn segment(position: f32, last: usize) -> (usize, f32) {
+    let scaled = position * last as f32;
+    let index = (scaled as usize).min(last - 1);
+    (index, scaled - index as f32)
+}
+
+#[test]
+fn max_duty() {
+    assert_eq!(PwmResolution::Bits8.max_duty(), 255);
+    assert_eq!(PwmResolution::Bits10.max_duty(), 1023);
+    assert_eq!(PwmResolution::Bits12.max_duty(), 4095);
+    assert_eq!(PwmResolution::Bits16.max_duty(), 65535);
+}
+
+#[test]
+fn linear() {
+    let curve = BrightnessCurve::Linear;
+    assert_eq!(curve.duty(50.0, PwmResolution::Bits8), 128);
+    assert_eq!(curve.duty(100.0, PwmResolution::Bits10), 1023);
+    float_eq::assert_float_eq!(
+        curve.brightness_from_duty(512, PwmResolution::Bits10),
+        50.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn gamma_is_flat_at_low_end() {
+    let curve = BrightnessCurve::Gamma(2.2);
+    assert_eq!(curve.duty(0.0, PwmResolution::Bits12), 0);
+    assert_eq!(curve.duty(10.0, PwmResolution::Bits12), 26);
+    assert_eq!(curve.duty(50.0, PwmResolution::Bits12), 891);
+    assert_eq!(curve.duty(100.0, PwmResolution::Bits12), 4095);
+}
+
+#[test]
+fn cie1976_known_values() {
+    let curve = BrightnessCurve::Cie1976;
+    float_eq::assert_float_eq!(curve.output(50.0), 0.184, abs <= 0.001);
+    float_eq::assert_float_eq!(curve.output(5.0), 0.005_5, abs <= 0.000_1);
+    float_eq::assert_float_eq!(curve.output(100.0), 1.0, abs <= 0.000_1);
+}
+
+#[test]
+fn out_of_range_is_clamped() {
+    let curve = BrightnessCurve::Cie1976;
+    assert_eq!(curve.duty(-5.0, PwmResolution::Bits8), 0);
+    assert_eq!(curve.duty(f32::NAN, PwmResolution::Bits8), 0);
+    assert_eq!(curve.duty(150.0, PwmResolution::Bits8), 255);
+}
+
+#[test]
+fn invalid_curve_is_clamped() {
+    let lookup = BrightnessCurve::Lookup(&[0.0, 2.0]);
+    assert_eq!(lookup.duty(100.0, PwmResolution::Bits8), 255);
+    let gamma = BrightnessCurve::Gamma(-1.0);
+    assert_eq!(gamma.duty(50.0, PwmResolution::Bits8), 255);
+    let negative = BrightnessCurve::Lookup(&[-1.0, 1.0]);
+    assert_eq!(negative.duty(0.0, PwmResolution::Bits8), 0);
+}
+
+#[test]
+fn lookup_interpolates() {
+    let curve = BrightnessCurve::Lookup(&[0.0, 0.1, 1.0]);
+    float_eq::assert_float_eq!(curve.output(25.0), 0.05, abs <= 0.000_1);
+    float_eq::assert_float_eq!(curve.output(75.0), 0.55, abs <= 0.000_1);
+    float_eq::assert_float_eq!(curve.output(100.0), 1.0, abs <= 0.000_1);
+}
+
+#[test]
+fn lookup_too_short_is_linear() {
+    let curve = BrightnessCurve::Lookup(&[0.5]);
+    float_eq::assert_float_eq!(curve.output(25.0), 0.25, abs <= 0.000_1);
+    float_eq::assert_float_eq!(curve.brightness(0.25), 25.0, abs <= 0.000_1);
+}
+
+#[test]
+fn roundtrip() {
+    let table = [0.0, 0.01, 0.05, 0.2, 0.5, 1.0];
+    for curve in [
+        BrightnessCurve::Linear,
+        BrightnessCurve::Gamma(2.8),
+        BrightnessCurve::Cie1976,
+        BrightnessCurve::Lookup(&table),
+    ] {
+        for brightness in [0.0, 1.0, 5.0, 33.0, 50.0, 90.0, 100.0] {
+            let output = curve.output(brightness);
+            float_eq::assert_float_eq!(curve.brightness(output), brightness, abs <= 0.01);
+        }
+    }
+}
+
+#[test]
+fn duty_roundtrip_16_bit() {
+    let curve = BrightnessCurve::Cie1976;
+    let duty = curve.duty(42.0, PwmResolution::Bits16);
+    float_eq::assert_float_eq!(
+        curve.brightness_from_duty(duty, PwmResolution::Bits16),
+        42.0,
+        abs <= 0.01
+    );
+}
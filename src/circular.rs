@@ -0,0 +1,210 @@
+//! Statistics of angles in degrees like hues or wind directions.
+//!
+//! Results are `None` for empty inputs.
+//! Angles which cancel each other out like 0° and 180° have no mean direction which also results in `None`.
+
+use crate::angle_distance;
+use crate::light::{abs, normalize_hue};
+
+/// Below this mean resultant length the angles cancel each other out
+const OPPOSING: f32 = 0.000_1;
+
+/// Sum of the weighted unit vectors and the sum of the weights
+fn resultant<I>(angles: I) -> Option<(f32, f32, f32)>
+where
+    I: IntoIterator<Item = (f32, f32)>,
+{
+    let mut sin = 0.0;
+    let mut cos = 0.0;
+    let mut weights = 0.0;
+    for (angle, weight) in angles {
+        let radians = angle.to_radians();
+        sin += libm::sinf(radians) * weight;
+        cos += libm::cosf(radians) * weight;
+        weights += weight;
+    }
+    (weights > 0.0).then_some((sin, cos, weights))
+}
+
+/// Circular mean from 0.0 to 360.0
+#[must_use]
+pub fn mean<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = f32>,
+{
+    weighted_mean(angles.into_iter().map(|angle| (angle, 1.0)))
+}
+
+/// Circular mean of `(angle, weight)` pairs from 0.0 to 360.0.
+///
+/// Weights should not be negative.
+#[must_use]
+pub fn weighted_mean<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = (f32, f32)>,
+{
+    let (sin, cos, weights) = resultant(angles)?;
+    if libm::hypotf(sin, cos) / weights < OPPOSING {
+        return None;
+    }
+    Some(normalize_hue(libm::atan2f(sin, cos).to_degrees()))
+}
+
+/// Mean resultant length from 0.0 when the angles cancel each other out to 1.0 when all are the same
+#[must_use]
+pub fn resultant_length<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = f32>,
+{
+    weighted_resultant_length(angles.into_iter().map(|angle| (angle, 1.0)))
+}
+
+/// Mean resultant length of `(angle, weight)` pairs, see [`resultant_length`]
+#[must_use]
+pub fn weighted_resultant_length<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = (f32, f32)>,
+{
+    let (sin, cos, weights) = resultant(angles)?;
+    Some((libm::hypotf(sin, cos) / weights).min(1.0))
+}
+
+/// Circular variance from 0.0 when all angles are the same to 1.0 when they cancel each other out
+#[must_use]
+pub fn variance<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = f32>,
+{
+    resultant_length(angles).map(|length| 1.0 - length)
+}
+
+/// Circular standard deviation in degrees.
+///
+/// Close to the linear standard deviation for small spreads and infinite when the angles cancel each other out.
+#[must_use]
+pub fn standard_deviation<I>(angles: I) -> Option<f32>
+where
+    I: IntoIterator<Item = f32>,
+{
+    let length = resultant_length(angles)?;
+    if length < OPPOSING {
+        return Some(f32::INFINITY);
+    }
+    Some(libm::sqrtf(-2.0 * libm::logf(length)).to_degrees())
+}
+
+/// Circular median which is the given angle with the smallest total distance to all others.
+///
+/// With an even number of angles or opposing angles the first of the equally good ones is returned.
+#[must_use]
+pub fn median(angles: &[f32]) -> Option<f32> {
+    let mut best: Option<(f32, f32)> = None;
+    for candidate in angles {
+        let total: f32 = angles
+            .iter()
+            .map(|angle| abs(angle_distance(*candidate, *angle)))
+            .sum();
+        if best.is_none_or(|(_, best_total)| total < best_total) {
+            best = Some((*candidate, total));
+        }
+    }
+    best.map(|(angle, _)| normalize_hue(angle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_over_zero() {
+        let result = mean([350.0, 10.0]).unwrap();
+        float_eq::assert_float_eq!(angle_distance(result, 0.0), 0.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn mean_three() {
+        float_eq::assert_float_eq!(mean([80.0, 90.0, 100.0]).unwrap(), 90.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn mean_empty() {
+        assert!(mean([]).is_none());
+    }
+
+    #[test]
+    fn mean_opposing() {
+        assert!(mean([0.0, 180.0]).is_none());
+        assert!(mean([0.0, 120.0, 240.0]).is_none());
+    }
+
+    #[test]
+    fn weighted_mean_leans_to_weight() {
+        let result = weighted_mean([(0.0, 3.0), (90.0, 1.0)]).unwrap();
+        float_eq::assert_float_eq!(result, 18.43, abs <= 0.01);
+    }
+
+    #[test]
+    fn weighted_mean_zero_weights() {
+        assert!(weighted_mean([(0.0, 0.0), (90.0, 0.0)]).is_none());
+    }
+
+    #[test]
+    fn weighted_resultant_length_ignores_zero_weight() {
+        let result = weighted_resultant_length([(0.0, 1.0), (180.0, 0.0)]).unwrap();
+        float_eq::assert_float_eq!(result, 1.0, abs <= 0.001);
+    }
+
+    #[test]
+    fn variance_same() {
+        float_eq::assert_float_eq!(variance([42.0, 42.0, 402.0]).unwrap(), 0.0, abs <= 0.001);
+    }
+
+    #[test]
+    fn variance_opposing() {
+        float_eq::assert_float_eq!(variance([0.0, 180.0]).unwrap(), 1.0, abs <= 0.001);
+    }
+
+    #[test]
+    fn variance_empty() {
+        assert!(variance([]).is_none());
+    }
+
+    #[test]
+    fn standard_deviation_small_spread() {
+        let result = standard_deviation([350.0, 10.0]).unwrap();
+        float_eq::assert_float_eq!(result, 10.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn standard_deviation_opposing() {
+        assert!(standard_deviation([90.0, 270.0]).unwrap().is_infinite());
+    }
+
+    #[test]
+    fn median_over_zero() {
+        float_eq::assert_float_eq!(
+            median(&[350.0, 355.0, 20.0, 5.0, 0.0]).unwrap(),
+            0.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn median_ignores_outlier() {
+        float_eq::assert_float_eq!(
+            median(&[10.0, 20.0, 30.0, 200.0, 25.0]).unwrap(),
+            25.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn median_normalizes() {
+        float_eq::assert_float_eq!(median(&[-10.0]).unwrap(), 350.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn median_empty() {
+        assert!(median(&[]).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{Hsv, Rgb, interpolate};
+
+/// Warmest color temperature the approximation is made for
+const MIN_KELVIN: f32 = 1000.0;
+/// Coldest color temperature the approximation is made for
+const MAX_KELVIN: f32 = 40000.0;
+
+#[derive(Clone, Copy)]
+pub struct ColorTemperature {
+    /// Color temperature in Kelvin like 2700.0 for warm white
+    pub kelvin: f32,
+}
+
+impl ColorTemperature {
+    #[must_use]
+    pub const fn from_kelvin(kelvin: f32) -> Self {
+        Self { kelvin }
+    }
+
+    /// Mired (micro reciprocal degree) is commonly used by Zigbee and Home Assistant
+    #[must_use]
+    pub fn from_mired(mired: f32) -> Self {
+        Self {
+            kelvin: 1_000_000.0 / mired,
+        }
+    }
+
+    #[must_use]
+    pub fn mired(self) -> f32 {
+        1_000_000.0 / self.kelvin
+    }
+
+    /// Clamps to the supported range of a device.
+    ///
+    /// The limits can be given in any order as devices often report them in mired
+    /// where `min_mireds` is the coldest and therefore the highest Kelvin.
+    #[must_use]
+    pub const fn clamp(self, limit: Self, other_limit: Self) -> Self {
+        let min = limit.kelvin.min(other_limit.kelvin);
+        let max = limit.kelvin.max(other_limit.kelvin);
+        Self {
+            kelvin: self.kelvin.max(min).min(max),
+        }
+    }
+
+    /// Interpolates in mired as that is perceived linear unlike Kelvin
+    #[must_use]
+    pub fn calculate_interpolated(start: Self, end: Self, position: f32) -> Self {
+        if position <= 0.0 {
+            start
+        } else if position >= 1.0 {
+            end
+        } else {
+            Self::from_mired(interpolate::f32(start.mired(), end.mired(), position))
+        }
+    }
+
+    /// Approximates the color of a black body with this temperature which follows the Planckian locus.
+    ///
+    /// Based on the approximation by Tanner Helland which is made for 1000 K to 40000 K.
+    /// The brightest channel is always 100.0.
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn to_rgb(self) -> Rgb {
+        let temperature = self.kelvin / 100.0;
+        let red = if temperature <= 66.0 {
+            255.0
+        } else {
+            329.698_73 * libm::powf(temperature - 60.0, -0.133_204_76)
+        };
+        let green = if temperature <= 66.0 {
+            99.470_8 * libm::logf(temperature) - 161.119_57
+        } else {
+            288.122_17 * libm::powf(temperature - 60.0, -0.075_514_85)
+        };
+        let blue = if temperature >= 66.0 {
+            255.0
+        } else if temperature <= 19.0 {
+            0.0
+        } else {
+            138.517_73 * libm::logf(temperature - 10.0) - 305.044_8
+        };
+        Rgb {
+            red: (red / 2.55).clamp(0.0, 100.0),
+            green: (green / 2.55).clamp(0.0, 100.0),
+            blue: (blue / 2.55).clamp(0.0, 100.0),
+        }
+    }
+
+    /// See [`ColorTemperature::to_rgb`]
+    #[must_use]
+    pub fn to_hsv(self) -> Hsv {
+        self.to_rgb().into()
+    }
+
+    /// Finds the color temperature which is closest to the given color.
+    ///
+    /// Only the balance between red and blue is considered, brightness is ignored.
+    /// The result is within 1000 K to 40000 K.
+    /// Below 1900 K the approximation contains no blue so these are all returned as 1000 K.
+    #[must_use]
+    pub fn from_hsv(hsv: &Hsv) -> Self {
+        let target = warmth(&Rgb::from(Hsv {
+            brightness: 100.0,
+            ..*hsv
+        }));
+        if target.is_nan() {
+            return Self::from_kelvin(MIN_KELVIN);
+        }
+
+        // Warmth only decreases with increasing temperature
+        let mut warm = MIN_KELVIN;
+        let mut cold = MAX_KELVIN;
+        for _ in 0..32 {
+            let middle = f32::midpoint(warm, cold);
+            if warmth(&Self::from_kelvin(middle).to_rgb()) > target {
+                warm = middle;
+            } else {
+                cold = middle;
+            }
+        }
+        Self::from_kelvin(f32::midpoint(warm, cold))
+    }
+}
+
+/// Difference between red and blue relative to the brightest channel
+fn warmth(rgb: &Rgb) -> f32 {
+    let max = rgb.red.max(rgb.green).max(rgb.blue);
+    (rgb.red - rgb.blue) / max
+}
+
+#[test]
+fn mired_conversion() {
+    float_eq::assert_float_eq!(
+        ColorTemperature::from_kelvin(2000.0).mired(),
+        500.0,
+        abs <= 0.1
+    );
+    float_eq::assert_float_eq!(
+        ColorTemperature::from_mired(153.0).kelvin,
+        6535.9,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn clamp_to_device() {
+    let min = ColorTemperature::from_mired(500.0);
+    let max = ColorTemperature::from_mired(153.0);
+    let result = ColorTemperature::from_kelvin(1500.0).clamp(min, max);
+    float_eq::assert_float_eq!(result.kelvin, 2000.0, abs <= 0.1);
+    let result = ColorTemperature::from_kelvin(3000.0).clamp(min, max);
+    float_eq::assert_float_eq!(result.kelvin, 3000.0, abs <= 0.1);
+}
+
+#[test]
+fn clamp_to_device_mired_order() {
+    let min_mireds = ColorTemperature::from_mired(153.0);
+    let max_mireds = ColorTemperature::from_mired(500.0);
+    let result = ColorTemperature::from_kelvin(3000.0).clamp(min_mireds, max_mireds);
+    float_eq::assert_float_eq!(result.kelvin, 3000.0, abs <= 0.1);
+    let result = ColorTemperature::from_kelvin(10000.0).clamp(min_mireds, max_mireds);
+    float_eq::assert_float_eq!(result.mired(), 153.0, abs <= 0.1);
+}
+
+#[test]
+fn neutral_is_white() {
+    let result = ColorTemperature::from_kelvin(6600.0).to_rgb();
+    float_eq::assert_float_eq!(result.red, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.green, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.blue, 100.0, abs <= 0.1);
+}
+
+#[test]
+fn warm_white_is_orange() {
+    let result = ColorTemperature::from_kelvin(2700.0).to_hsv();
+    float_eq::assert_float_eq!(result.hue, 30.0, abs <= 5.0);
+    assert!(result.saturation > 50.0);
+    float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.1);
+}
+
+#[test]
+fn cold_white_is_blue() {
+    let result = ColorTemperature::from_kelvin(15000.0).to_hsv();
+    float_eq::assert_float_eq!(result.hue, 220.0, abs <= 10.0);
+}
+
+#[test]
+fn nearest_roundtrip() {
+    for kelvin in [2200.0, 2700.0, 4000.0, 6500.0, 10000.0] {
+        let hsv = ColorTemperature::from_kelvin(kelvin).to_hsv();
+        let result = ColorTemperature::from_hsv(&hsv);
+        float_eq::assert_float_eq!(result.kelvin, kelvin, r2nd <= 0.01);
+    }
+}
+
+#[test]
+fn nearest_ignores_brightness() {
+    let hsv = Hsv {
+        brightness: 20.0,
+        ..ColorTemperature::from_kelvin(3000.0).to_hsv()
+    };
+    let result = ColorTemperature::from_hsv(&hsv);
+    float_eq::assert_float_eq!(result.kelvin, 3000.0, r2nd <= 0.01);
+}
+
+#[test]
+fn nearest_of_red_is_warmest() {
+    let result = ColorTemperature::from_hsv(&Hsv::from_hue(0.0));
+    float_eq::assert_float_eq!(result.kelvin, MIN_KELVIN, abs <= 1.0);
+}
+
+#[cfg(test)]
+mod interpolate_tests {
+    use super::ColorTemperature;
+
+    const START: ColorTemperature = ColorTemperature::from_kelvin(2000.0);
+    const END: ColorTemperature = ColorTemperature::from_kelvin(5000.0);
+
+    #[test]
+    fn before_zero() {
+        let result = ColorTemperature::calculate_interpolated(START, END, -1.0);
+        float_eq::assert_float_eq!(result.kelvin, 2000.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn half_is_in_mired() {
+        // 500 mired to 200 mired
+        let result = ColorTemperature::calculate_interpolated(START, END, 0.5);
+        float_eq::assert_float_eq!(result.mired(), 350.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn after_one() {
+        let result = ColorTemperature::calculate_interpolated(START, END, 2.0);
+        float_eq::assert_float_eq!(result.kelvin, 5000.0, abs <= 0.1);
+    }
+}
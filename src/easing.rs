@@ -0,0 +1,221 @@
+use core::f32::consts::PI;
+
+/// Shapes the position of an interpolation.
+///
+/// Apply it to the position before interpolating, for example
+/// `interpolate::f32(start, end, Easing::CubicInOut.apply(position))` or
+/// `Hsv::calculate_interpolated(&start, &end, Easing::SineInOut.apply(position))`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    Smoothstep,
+    /// Like the CSS `cubic-bezier()` with the control points `(x1, y1)` and `(x2, y2)`.
+    /// `x1` and `x2` have to be within 0.0 to 1.0.
+    CubicBezier {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    },
+}
+
+impl Easing {
+    /// Eases the position from 0.0 to 1.0. Positions outside are clamped.
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn apply(self, position: f32) -> f32 {
+        if position.is_nan() || position <= 0.0 {
+            return 0.0;
+        }
+        if position >= 1.0 {
+            return 1.0;
+        }
+        let inverse = 1.0 - position;
+        match self {
+            Self::Linear => position,
+            Self::QuadIn => position * position,
+            Self::QuadOut => 1.0 - inverse * inverse,
+            Self::QuadInOut => {
+                if position < 0.5 {
+                    2.0 * position * position
+                } else {
+                    1.0 - 2.0 * inverse * inverse
+                }
+            }
+            Self::CubicIn => position * position * position,
+            Self::CubicOut => 1.0 - inverse * inverse * inverse,
+            Self::CubicInOut => {
+                if position < 0.5 {
+                    4.0 * position * position * position
+                } else {
+                    1.0 - 4.0 * inverse * inverse * inverse
+                }
+            }
+            Self::SineIn => 1.0 - libm::cosf(position * PI / 2.0),
+            Self::SineOut => libm::sinf(position * PI / 2.0),
+            Self::SineInOut => (1.0 - libm::cosf(position * PI)) / 2.0,
+            Self::ExpoIn => libm::exp2f(10.0 * position - 10.0),
+            Self::ExpoOut => 1.0 - libm::exp2f(-10.0 * position),
+            Self::ExpoInOut => {
+                if position < 0.5 {
+                    libm::exp2f(20.0 * position - 10.0) / 2.0
+                } else {
+                    1.0 - libm::exp2f(-20.0 * position + 10.0) / 2.0
+                }
+            }
+            Self::Smoothstep => position * position * (3.0 - 2.0 * position),
+            Self::CubicBezier { x1, y1, x2, y2 } => {
+                let curve = solve_bezier(position, x1, x2);
+                bezier(curve, y1, y2)
+            }
+        }
+    }
+}
+
+/// One dimension of a cubic bezier from 0.0 to 1.0 with the given control points
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+fn bezier(curve: f32, first: f32, second: f32) -> f32 {
+    let inverse = 1.0 - curve;
+    3.0 * inverse * inverse * curve * first
+        + 3.0 * inverse * curve * curve * second
+        + curve * curve * curve
+}
+
+/// Finds the curve parameter where the x dimension of the bezier is the given position
+fn solve_bezier(position: f32, x1: f32, x2: f32) -> f32 {
+    let mut lower = 0.0;
+    let mut upper = 1.0;
+    let mut curve = position;
+    for _ in 0..32 {
+        let current = bezier(curve, x1, x2);
+        if current < position {
+            lower = curve;
+        } else {
+            upper = curve;
+        }
+        curve = f32::midpoint(lower, upper);
+    }
+    curve
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Easing; 15] = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ExpoIn,
+        Easing::ExpoOut,
+        Easing::ExpoInOut,
+        Easing::Smoothstep,
+        Easing::CubicBezier {
+            x1: 0.25,
+            y1: 0.1,
+            x2: 0.25,
+            y2: 1.0,
+        },
+    ];
+
+    #[test]
+    fn endpoints() {
+        for easing in ALL {
+            float_eq::assert_float_eq!(easing.apply(0.0), 0.0, abs <= 0.001);
+            float_eq::assert_float_eq!(easing.apply(1.0), 1.0, abs <= 0.001);
+        }
+    }
+
+    #[test]
+    fn outside_is_clamped() {
+        for easing in ALL {
+            float_eq::assert_float_eq!(easing.apply(-1.0), 0.0, abs <= 0.001);
+            float_eq::assert_float_eq!(easing.apply(2.0), 1.0, abs <= 0.001);
+            float_eq::assert_float_eq!(easing.apply(f32::NAN), 0.0, abs <= 0.001);
+        }
+    }
+
+    #[test]
+    fn increasing() {
+        for easing in ALL {
+            let mut last = 0.0;
+            for step in 1..=100_u8 {
+                let current = easing.apply(f32::from(step) / 100.0);
+                assert!(current >= last, "{easing:?} decreased at {step}");
+                last = current;
+            }
+        }
+    }
+
+    #[test]
+    fn in_out_is_symmetric() {
+        for easing in [
+            Easing::QuadInOut,
+            Easing::CubicInOut,
+            Easing::SineInOut,
+            Easing::ExpoInOut,
+            Easing::Smoothstep,
+        ] {
+            float_eq::assert_float_eq!(easing.apply(0.5), 0.5, abs <= 0.001);
+            float_eq::assert_float_eq!(easing.apply(0.2), 1.0 - easing.apply(0.8), abs <= 0.001);
+        }
+    }
+
+    #[test]
+    fn quad_in() {
+        float_eq::assert_float_eq!(Easing::QuadIn.apply(0.5), 0.25, abs <= 0.001);
+    }
+
+    #[test]
+    fn cubic_out() {
+        float_eq::assert_float_eq!(Easing::CubicOut.apply(0.5), 0.875, abs <= 0.001);
+    }
+
+    #[test]
+    fn bezier_linear() {
+        let easing = Easing::CubicBezier {
+            x1: 0.0,
+            y1: 0.0,
+            x2: 1.0,
+            y2: 1.0,
+        };
+        float_eq::assert_float_eq!(easing.apply(0.3), 0.3, abs <= 0.001);
+    }
+
+    #[test]
+    fn bezier_css_ease_in_out() {
+        let easing = Easing::CubicBezier {
+            x1: 0.42,
+            y1: 0.0,
+            x2: 0.58,
+            y2: 1.0,
+        };
+        float_eq::assert_float_eq!(easing.apply(0.5), 0.5, abs <= 0.001);
+        float_eq::assert_float_eq!(easing.apply(0.25), 0.129, abs <= 0.001);
+    }
+
+    #[test]
+    fn with_interpolate() {
+        let result = crate::interpolate::u8(0, 100, Easing::QuadIn.apply(0.5));
+        assert_eq!(result, 25);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Easing, Hsv};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Position from 0.0 to 1.0
+    pub position: f32,
+    pub color: Hsv,
+    /// Easing of the segment towards the next stop
+    pub easing: Easing,
+}
+
+/// Colors at positions from 0.0 to 1.0 with interpolation between them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient<'stops> {
+    /// Stops sorted by their position
+    pub stops: &'stops [GradientStop],
+    /// Wraps around from the last stop to the first one like a hue rotation.
+    /// Positions outside of 0.0 to 1.0 wrap around too.
+    pub cyclic: bool,
+}
+
+impl Gradient<'_> {
+    /// Color at the given position or `None` when there are no stops.
+    ///
+    /// Non cyclic gradients use the first or last color before or after the stops.
+    #[must_use]
+    pub fn sample(&self, position: f32) -> Option<Hsv> {
+        let first = self.stops.first()?;
+        let last = self.stops.last()?;
+
+        if self.cyclic {
+            let mut position = position % 1.0;
+            if position.is_sign_negative() {
+                position += 1.0;
+            }
+            if position < first.position {
+                position += 1.0;
+            }
+            if position >= last.position {
+                let wrapped = GradientStop {
+                    position: first.position + 1.0,
+                    ..*first
+                };
+                return Some(interpolate_segment(last, &wrapped, position));
+            }
+            return Some(self.sample_between(position));
+        }
+
+        if position <= first.position {
+            Some(first.color)
+        } else if position >= last.position {
+            Some(last.color)
+        } else {
+            Some(self.sample_between(position))
+        }
+    }
+
+    /// Position needs to be between the first and last stop
+    fn sample_between(&self, position: f32) -> Hsv {
+        self.stops
+            .windows(2)
+            .find_map(|pair| match pair {
+                [start, end] if position < end.position => {
+                    Some(interpolate_segment(start, end, position))
+                }
+                _ => None,
+            })
+            .unwrap_or(self.stops[self.stops.len() - 1].color)
+    }
+}
+
+fn interpolate_segment(start: &GradientStop, end: &GradientStop, position: f32) -> Hsv {
+    let length = end.position - start.position;
+    let local = if length > 0.0 {
+        (position - start.position) / length
+    } else {
+        1.0
+    };
+    Hsv::calculate_interpolated(&start.color, &end.color, start.easing.apply(local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn stop(position: f32, hue: f32) -> GradientStop {
+        GradientStop {
+            position,
+            color: Hsv::from_hue(hue),
+            easing: Easing::Linear,
+        }
+    }
+
+    const STOPS: [GradientStop; 3] = [stop(0.0, 0.0), stop(0.5, 60.0), stop(1.0, 120.0)];
+
+    #[test]
+    fn empty() {
+        let gradient = Gradient {
+            stops: &[],
+            cyclic: false,
+        };
+        assert!(gradient.sample(0.5).is_none());
+    }
+
+    #[test]
+    fn single_stop() {
+        let stops = [stop(0.3, 42.0)];
+        for cyclic in [false, true] {
+            let gradient = Gradient {
+                stops: &stops,
+                cyclic,
+            };
+            for position in [0.0, 0.3, 0.7, 1.0] {
+                let result = gradient.sample(position).unwrap();
+                float_eq::assert_float_eq!(result.hue, 42.0, abs <= 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn on_stops() {
+        let gradient = Gradient {
+            stops: &STOPS,
+            cyclic: false,
+        };
+        float_eq::assert_float_eq!(gradient.sample(0.0).unwrap().hue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(0.5).unwrap().hue, 60.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(1.0).unwrap().hue, 120.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn between_stops() {
+        let gradient = Gradient {
+            stops: &STOPS,
+            cyclic: false,
+        };
+        float_eq::assert_float_eq!(gradient.sample(0.25).unwrap().hue, 30.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(0.75).unwrap().hue, 90.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn outside_is_clamped() {
+        let gradient = Gradient {
+            stops: &STOPS,
+            cyclic: false,
+        };
+        float_eq::assert_float_eq!(gradient.sample(-1.0).unwrap().hue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(2.0).unwrap().hue, 120.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn segment_easing() {
+        let stops = [
+            GradientStop {
+                easing: Easing::QuadIn,
+                ..stop(0.0, 0.0)
+            },
+            stop(1.0, 100.0),
+        ];
+        let gradient = Gradient {
+            stops: &stops,
+            cyclic: false,
+        };
+        float_eq::assert_float_eq!(gradient.sample(0.5).unwrap().hue, 25.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn cyclic_wraps_from_last_to_first() {
+        let stops = [
+            stop(0.0, 0.0),
+            stop(0.25, 90.0),
+            stop(0.5, 180.0),
+            stop(0.75, 270.0),
+        ];
+        let gradient = Gradient {
+            stops: &stops,
+            cyclic: true,
+        };
+        float_eq::assert_float_eq!(gradient.sample(0.875).unwrap().hue, 315.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(1.125).unwrap().hue, 45.0, abs <= 0.1);
+        float_eq::assert_float_eq!(gradient.sample(-0.125).unwrap().hue, 315.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn cyclic_before_first_stop() {
+        let stops = [stop(0.2, 100.0), stop(0.6, 200.0)];
+        let gradient = Gradient {
+            stops: &stops,
+            cyclic: true,
+        };
+        // Wrap segment goes from 0.6 to 1.2 so 0.0 is at two thirds
+        let result = gradient.sample(0.0).unwrap();
+        float_eq::assert_float_eq!(result.hue, 200.0 - (100.0 * 2.0 / 3.0), abs <= 0.1);
+    }
+}
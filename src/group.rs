@@ -0,0 +1,210 @@
+use crate::light::abs;
+use crate::{Hsv, angle_distance, circular};
+
+/// How [`GroupStrategy::combine`] chooses the color of a group of lights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupStrategy {
+    /// Circular hue mean weighted by saturation and brightness, saturation and brightness are averaged
+    Mean,
+    /// Color of the brightest member
+    MaxBrightness,
+    /// Color which the most members have
+    MostCommon,
+}
+
+/// Representative color of a group of lights
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroupState {
+    pub color: Hsv,
+    /// How far the members disagree from 0.0 when all are the same to 1.0.
+    ///
+    /// The largest of the brightness spread, the saturation spread and the circular hue variance of colored members.
+    pub disagreement: f32,
+}
+
+impl GroupStrategy {
+    /// Combines the members or `None` for an empty group
+    #[must_use]
+    pub fn combine(self, members: &[Hsv]) -> Option<GroupState> {
+        let color = match self {
+            Self::Mean => mean(members)?,
+            Self::MaxBrightness => *members.iter().reduce(|best, hsv| {
+                if hsv.brightness > best.brightness {
+                    hsv
+                } else {
+                    best
+                }
+            })?,
+            Self::MostCommon => most_common(members)?,
+        };
+        Some(GroupState {
+            color,
+            disagreement: disagreement(members),
+        })
+    }
+}
+
+/// Weight of the hue which is not visible without saturation or brightness
+fn chroma(hsv: &Hsv) -> f32 {
+    (hsv.saturation * hsv.brightness / 10_000.0).max(0.0)
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn mean(members: &[Hsv]) -> Option<Hsv> {
+    let first = members.first()?;
+    let count = members.len() as f32;
+    let hue = circular::weighted_mean(members.iter().map(|hsv| (hsv.hue, chroma(hsv))))
+        .or_else(|| circular::mean(members.iter().map(|hsv| hsv.hue)))
+        .unwrap_or(first.hue);
+    Some(Hsv {
+        hue,
+        saturation: members.iter().map(|hsv| hsv.saturation).sum::<f32>() / count,
+        brightness: members.iter().map(|hsv| hsv.brightness).sum::<f32>() / count,
+    })
+}
+
+/// Member with the most other members within one unit, the first one on ties
+fn most_common(members: &[Hsv]) -> Option<Hsv> {
+    let similar = |one: &Hsv, other: &Hsv| {
+        abs(angle_distance(one.hue, other.hue)) <= 1.0
+            && abs(one.saturation - other.saturation) <= 1.0
+            && abs(one.brightness - other.brightness) <= 1.0
+    };
+    let mut best: Option<(&Hsv, usize)> = None;
+    for candidate in members {
+        let count = members
+            .iter()
+            .filter(|other| similar(candidate, other))
+            .count();
+        if best.is_none_or(|(_, best_count)| count > best_count) {
+            best = Some((candidate, count));
+        }
+    }
+    best.map(|(hsv, _)| *hsv)
+}
+
+fn disagreement(members: &[Hsv]) -> f32 {
+    let spread = |value: fn(&Hsv) -> f32| {
+        let min = members.iter().map(value).fold(f32::INFINITY, f32::min);
+        let max = members.iter().map(value).fold(f32::NEG_INFINITY, f32::max);
+        ((max - min) / 100.0).clamp(0.0, 1.0)
+    };
+    let hue = circular::weighted_resultant_length(members.iter().map(|hsv| (hsv.hue, chroma(hsv))))
+        .map_or(0.0, |length| 1.0 - length);
+    hue.max(spread(|hsv| hsv.saturation))
+        .max(spread(|hsv| hsv.brightness))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn hsv(hue: f32, saturation: f32, brightness: f32) -> Hsv {
+        Hsv {
+            hue,
+            saturation,
+            brightness,
+        }
+    }
+
+    const ALL: [GroupStrategy; 3] = [
+        GroupStrategy::Mean,
+        GroupStrategy::MaxBrightness,
+        GroupStrategy::MostCommon,
+    ];
+
+    #[test]
+    fn empty() {
+        for strategy in ALL {
+            assert!(strategy.combine(&[]).is_none());
+        }
+    }
+
+    #[test]
+    fn same_members_agree() {
+        let members = [hsv(120.0, 80.0, 60.0); 3];
+        for strategy in ALL {
+            let result = strategy.combine(&members).unwrap();
+            float_eq::assert_float_eq!(result.color.hue, 120.0, abs <= 0.01);
+            float_eq::assert_float_eq!(result.color.saturation, 80.0, abs <= 0.01);
+            float_eq::assert_float_eq!(result.color.brightness, 60.0, abs <= 0.01);
+            float_eq::assert_float_eq!(result.disagreement, 0.0, abs <= 0.001);
+        }
+    }
+
+    #[test]
+    fn mean_hue_over_zero() {
+        let members = [hsv(350.0, 100.0, 100.0), hsv(10.0, 100.0, 50.0)];
+        let result = GroupStrategy::Mean.combine(&members).unwrap();
+        assert!(
+            angle_distance(0.0, result.color.hue) < 0.0,
+            "{}",
+            result.color.hue
+        );
+        assert!(
+            angle_distance(350.0, result.color.hue) > 0.0,
+            "{}",
+            result.color.hue
+        );
+        float_eq::assert_float_eq!(result.color.brightness, 75.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn mean_ignores_hue_of_white() {
+        let members = [hsv(0.0, 0.0, 100.0), hsv(240.0, 100.0, 100.0)];
+        let result = GroupStrategy::Mean.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.color.hue, 240.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.color.saturation, 50.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn mean_all_gray_keeps_hue() {
+        let members = [hsv(30.0, 0.0, 100.0), hsv(30.0, 0.0, 0.0)];
+        let result = GroupStrategy::Mean.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.color.hue, 30.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn max_brightness() {
+        let members = [
+            hsv(0.0, 100.0, 20.0),
+            hsv(120.0, 50.0, 90.0),
+            hsv(240.0, 100.0, 40.0),
+        ];
+        let result = GroupStrategy::MaxBrightness.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.color.hue, 120.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.color.brightness, 90.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn most_common() {
+        let members = [
+            hsv(0.0, 100.0, 20.0),
+            hsv(120.0, 50.0, 90.0),
+            hsv(120.5, 50.0, 90.0),
+        ];
+        let result = GroupStrategy::MostCommon.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.color.hue, 120.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn disagreement_brightness() {
+        let members = [hsv(0.0, 100.0, 20.0), hsv(0.0, 100.0, 70.0)];
+        let result = GroupStrategy::Mean.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.disagreement, 0.5, abs <= 0.001);
+    }
+
+    #[test]
+    fn disagreement_opposing_hue() {
+        let members = [hsv(0.0, 100.0, 100.0), hsv(180.0, 100.0, 100.0)];
+        let result = GroupStrategy::MaxBrightness.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.disagreement, 1.0, abs <= 0.001);
+    }
+
+    #[test]
+    fn disagreement_ignores_hue_of_black() {
+        let members = [hsv(0.0, 100.0, 0.0), hsv(180.0, 100.0, 0.0)];
+        let result = GroupStrategy::Mean.combine(&members).unwrap();
+        float_eq::assert_float_eq!(result.disagreement, 0.0, abs <= 0.001);
+    }
+}
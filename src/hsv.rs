@@ -1,7 +1,7 @@
-use crate::angle_distance;
-use crate::light::normalize_hue;
+use crate::light::{abs, normalize_hue};
+use crate::{BrightnessCurve, HueDirection, PwmResolution, Rgb, angle_distance};
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Hsv {
     /// Hue from 0.0 to 360.0
     pub hue: f32,
@@ -11,7 +11,86 @@ pub struct Hsv {
     pub brightness: f32,
 }
 
+/// Component of [`Hsv::new`] which is out of range or NaN
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HsvError {
+    Hue(f32),
+    Saturation(f32),
+    Brightness(f32),
+}
+
+impl core::fmt::Display for HsvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Hue(hue) => write!(f, "hue {hue} is not within 0 to 360"),
+            Self::Saturation(saturation) => {
+                write!(f, "saturation {saturation} is not within 0 to 100")
+            }
+            Self::Brightness(brightness) => {
+                write!(f, "brightness {brightness} is not within 0 to 100")
+            }
+        }
+    }
+}
+
+impl core::error::Error for HsvError {}
+
+/// How [`Hsv::approach_with_hue_mode`] moves the hue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HueMode {
+    /// Hue moves like saturation and brightness
+    Always,
+    /// Hue is not visible while saturation or brightness is zero so it does not move there.
+    ///
+    /// Starting without a visible hue jumps to the target hue.
+    /// Approaching a target without visible hue keeps the current hue.
+    SkipAchromatic,
+}
+
 impl Hsv {
+    /// Checked constructor. Hue needs to be within 0.0 to 360.0, saturation and brightness within 0.0 to 100.0.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first component which is out of range or NaN.
+    pub fn new(hue: f32, saturation: f32, brightness: f32) -> Result<Self, HsvError> {
+        if !(0.0..360.0).contains(&hue) {
+            return Err(HsvError::Hue(hue));
+        }
+        if !(0.0..=100.0).contains(&saturation) {
+            return Err(HsvError::Saturation(saturation));
+        }
+        if !(0.0..=100.0).contains(&brightness) {
+            return Err(HsvError::Brightness(brightness));
+        }
+        Ok(Self {
+            hue,
+            saturation,
+            brightness,
+        })
+    }
+
+    /// Wraps the hue into 0.0 to below 360.0 and clamps saturation and brightness to 0.0 to 100.0.
+    ///
+    /// NaN and infinite hues become 0.0. The result is always accepted by [`Hsv::new`].
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        let sanitize = |value: f32| if value.is_nan() { 0.0 } else { value };
+        Self {
+            hue: sanitize(normalize_hue(self.hue)),
+            saturation: sanitize(self.saturation).clamp(0.0, 100.0),
+            brightness: sanitize(self.brightness).clamp(0.0, 100.0),
+        }
+    }
+
+    /// Every component is within the tolerance. Hue is compared the shorter way around.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        abs(angle_distance(self.hue, other.hue)) <= tolerance
+            && abs(self.saturation - other.saturation) <= tolerance
+            && abs(self.brightness - other.brightness) <= tolerance
+    }
+
     #[must_use]
     pub const fn from_hue(hue: f32) -> Self {
         Self {
@@ -30,50 +109,180 @@ impl Hsv {
         }
     }
 
-    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    /// Interpolates with the shortest way around the hue circle
     #[must_use]
     pub fn calculate_interpolated(start: &Self, end: &Self, position: f32) -> Self {
+        Self::calculate_interpolated_in_direction(start, end, position, HueDirection::Shortest)
+    }
+
+    /// Interpolates with the hue going around the circle in the given direction
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn calculate_interpolated_in_direction(
+        start: &Self,
+        end: &Self,
+        position: f32,
+        direction: HueDirection,
+    ) -> Self {
         if position <= 0.0 {
             *start
         } else if position >= 1.0 {
             *end
         } else {
             let distances = start.calculate_distance_to(end);
+            let hue = direction.distance(start.hue, end.hue);
             Self {
-                hue: normalize_hue((distances.hue * position) + start.hue),
+                hue: normalize_hue((hue * position) + start.hue),
                 saturation: (distances.saturation * position) + start.saturation,
                 brightness: (distances.brightness * position) + start.brightness,
             }
         }
     }
 
+    /// Approach the target so that hue, saturation and brightness arrive at the same time.
+    ///
+    /// The component with the largest distance moves by `step_size`, the others move proportionally slower.
+    #[must_use]
+    pub fn approach(&self, target: &Self, step_size: f32) -> Self {
+        self.approach_with_hue_mode(target, step_size, HueMode::Always)
+    }
+
+    /// Like [`Hsv::approach`] with control over the hue movement
+    #[must_use]
+    pub fn approach_with_hue_mode(&self, target: &Self, step_size: f32, mode: HueMode) -> Self {
+        let mut start = *self;
+        let mut target = *target;
+        if mode == HueMode::SkipAchromatic {
+            if start.is_achromatic() {
+                start.hue = target.hue;
+            } else if target.is_achromatic() {
+                target.hue = start.hue;
+            }
+        }
+
+        let distances = start.calculate_distance_to(&target);
+        let largest = abs(distances.hue)
+            .max(abs(distances.saturation))
+            .max(abs(distances.brightness));
+        if distances.hue.is_nan()
+            || distances.saturation.is_nan()
+            || distances.brightness.is_nan()
+            || largest <= step_size
+        {
+            return target;
+        }
+        Self::calculate_interpolated(&start, &target, step_size / largest)
+    }
+
+    const fn is_achromatic(&self) -> bool {
+        self.saturation <= 0.0 || self.brightness <= 0.0
+    }
+
+    /// Converts from `u8` RGB values
+    #[must_use]
+    pub fn from_rgb_u8(red: u8, green: u8, blue: u8) -> Self {
+        const MAX: f32 = u8::MAX as f32;
+        Self::from_rgb_f32(
+            f32::from(red) / MAX,
+            f32::from(green) / MAX,
+            f32::from(blue) / MAX,
+        )
+    }
+
+    /// Converts from `u16` RGB values
+    #[must_use]
+    pub fn from_rgb_u16(red: u16, green: u16, blue: u16) -> Self {
+        const MAX: f32 = u16::MAX as f32;
+        Self::from_rgb_f32(
+            f32::from(red) / MAX,
+            f32::from(green) / MAX,
+            f32::from(blue) / MAX,
+        )
+    }
+
     /// Converts from `f32` RGB values in the range of 0.0..1.0
-    #[cfg(feature = "bracket-color")]
     #[must_use]
     pub fn from_rgb_f32(red: f32, green: f32, blue: f32) -> Self {
-        let hsv = bracket_color::rgb::RGB::from_f32(red, green, blue).to_hsv();
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+
+        let hue = if delta <= 0.0 {
+            0.0
+        } else if red >= green && red >= blue {
+            60.0 * ((green - blue) / delta)
+        } else if green >= blue {
+            60.0 * (((blue - red) / delta) + 2.0)
+        } else {
+            60.0 * (((red - green) / delta) + 4.0)
+        };
+        let saturation = if max <= 0.0 { 0.0 } else { delta / max };
+
         Self {
-            hue: hsv.h * 360.0,
-            saturation: hsv.s * 100.0,
-            brightness: hsv.v * 100.0,
+            hue: normalize_hue(hue),
+            saturation: saturation * 100.0,
+            brightness: max * 100.0,
         }
     }
 
     /// Converts to `u8` RGB values
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    #[cfg(feature = "bracket-color")]
     #[must_use]
     pub fn to_rgb_u8(self) -> (u8, u8, u8) {
-        let hsv = bracket_color::hsv::HSV::from_f32(
-            self.hue / 360.0,
-            self.saturation / 100.0,
-            self.brightness / 100.0,
-        );
-        let rgb = hsv.to_rgb();
-        let red = (rgb.r * 255.0) as u8;
-        let green = (rgb.g * 255.0) as u8;
-        let blue = (rgb.b * 255.0) as u8;
-        (red, green, blue)
+        const MAX: f32 = u8::MAX as f32;
+        let (red, green, blue) = self.to_rgb_f32();
+        ((red * MAX) as u8, (green * MAX) as u8, (blue * MAX) as u8)
+    }
+
+    /// Converts to `u16` RGB values
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn to_rgb_u16(self) -> (u16, u16, u16) {
+        const MAX: f32 = u16::MAX as f32;
+        let (red, green, blue) = self.to_rgb_f32();
+        (
+            (red * MAX) as u16,
+            (green * MAX) as u16,
+            (blue * MAX) as u16,
+        )
+    }
+
+    /// Converts to RGB PWM duty cycles with the brightness curve applied to each channel
+    #[must_use]
+    pub fn to_rgb_duty(
+        self,
+        curve: &BrightnessCurve,
+        resolution: PwmResolution,
+    ) -> (u16, u16, u16) {
+        Rgb::from(self).to_duty(curve, resolution)
+    }
+
+    /// Converts to `f32` RGB values in the range of 0.0..1.0
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn to_rgb_f32(self) -> (f32, f32, f32) {
+        let value = self.brightness / 100.0;
+        let chroma = value * (self.saturation / 100.0);
+
+        // Sector of the hue circle from 0 to 5. 360° is the same as 0°.
+        let hue = normalize_hue(self.hue) / 60.0;
+        let sector = hue as u8;
+        let fraction = hue - f32::from(sector);
+
+        let rising = chroma * fraction;
+        let falling = chroma - rising;
+        let (red, green, blue) = match sector {
+            0 => (chroma, rising, 0.0),
+            1 => (falling, chroma, 0.0),
+            2 => (0.0, chroma, rising),
+            3 => (0.0, falling, chroma),
+            4 => (rising, 0.0, chroma),
+            5 => (chroma, 0.0, falling),
+            _ => (chroma, 0.0, 0.0),
+        };
+
+        let min = value - chroma;
+        (red + min, green + min, blue + min)
     }
 }
 
@@ -85,7 +294,6 @@ fn from_hue() {
     float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.1);
 }
 
-#[cfg(feature = "bracket-color")]
 #[test]
 fn from_rgb() {
     let result = Hsv::from_rgb_f32(1.0, 0.0, 0.0);
@@ -94,10 +302,187 @@ fn from_rgb() {
     float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.1);
 }
 
+#[test]
+fn from_rgb_u8_orange() {
+    let result = Hsv::from_rgb_u8(255, 128, 0);
+    float_eq::assert_float_eq!(result.hue, 30.0, abs <= 0.5);
+    float_eq::assert_float_eq!(result.saturation, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.1);
+}
+
+#[test]
+fn from_rgb_u16_magenta() {
+    let result = Hsv::from_rgb_u16(u16::MAX / 2, 0, u16::MAX / 2);
+    float_eq::assert_float_eq!(result.hue, 300.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.saturation, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.1);
+}
+
+#[test]
+fn from_rgb_gray_has_no_saturation() {
+    let result = Hsv::from_rgb_f32(0.5, 0.5, 0.5);
+    float_eq::assert_float_eq!(result.hue, 0.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.saturation, 0.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.1);
+}
+
+#[test]
+fn from_rgb_black() {
+    let result = Hsv::from_rgb_f32(0.0, 0.0, 0.0);
+    float_eq::assert_float_eq!(result.saturation, 0.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.brightness, 0.0, abs <= 0.1);
+}
+
+#[test]
+fn rgb_roundtrip() {
+    for hue in [0.0, 42.0, 90.0, 180.0, 222.0, 300.0, 359.0] {
+        let hsv = Hsv {
+            hue,
+            saturation: 75.0,
+            brightness: 60.0,
+        };
+        let (red, green, blue) = hsv.to_rgb_f32();
+        let result = Hsv::from_rgb_f32(red, green, blue);
+        float_eq::assert_float_eq!(result.hue, hue, abs <= 0.1);
+        float_eq::assert_float_eq!(result.saturation, 75.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 60.0, abs <= 0.1);
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use super::*;
+
+    #[test]
+    fn new_valid() {
+        let result = Hsv::new(120.0, 50.0, 100.0).unwrap();
+        assert_eq!(
+            result,
+            Hsv {
+                hue: 120.0,
+                saturation: 50.0,
+                brightness: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn new_out_of_range() {
+        assert_eq!(Hsv::new(720.0, 50.0, 50.0), Err(HsvError::Hue(720.0)));
+        assert_eq!(Hsv::new(360.0, 50.0, 50.0), Err(HsvError::Hue(360.0)));
+        assert_eq!(Hsv::new(0.0, -5.0, 50.0), Err(HsvError::Saturation(-5.0)));
+        assert_eq!(Hsv::new(0.0, 50.0, 100.5), Err(HsvError::Brightness(100.5)));
+    }
+
+    #[test]
+    fn new_nan() {
+        assert!(matches!(
+            Hsv::new(0.0, 50.0, f32::NAN),
+            Err(HsvError::Brightness(_))
+        ));
+        assert!(matches!(
+            Hsv::new(f32::NAN, 50.0, 50.0),
+            Err(HsvError::Hue(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            HsvError::Saturation(-5.0).to_string(),
+            "saturation -5 is not within 0 to 100"
+        );
+    }
+
+    #[test]
+    fn normalized() {
+        let result = Hsv {
+            hue: 720.0 + 30.0,
+            saturation: -5.0,
+            brightness: 150.0,
+        }
+        .normalized();
+        float_eq::assert_float_eq!(result.hue, 30.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.saturation, 0.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn normalized_negative_hue() {
+        let result = Hsv::from_hue(-90.0).normalized();
+        float_eq::assert_float_eq!(result.hue, 270.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn normalized_negative_zero_hue() {
+        for hue in [-0.0, -0.000_001] {
+            let result = Hsv::from_hue(hue).normalized();
+            float_eq::assert_float_eq!(result.hue, 0.0, abs <= 0.01);
+            assert!(Hsv::new(result.hue, result.saturation, result.brightness).is_ok());
+        }
+    }
+
+    #[test]
+    fn normalized_nan_and_infinity() {
+        let result = Hsv {
+            hue: f32::INFINITY,
+            saturation: f32::NAN,
+            brightness: f32::INFINITY,
+        }
+        .normalized();
+        assert_eq!(
+            result,
+            Hsv {
+                hue: 0.0,
+                saturation: 0.0,
+                brightness: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn default_is_black() {
+        let result = Hsv::default();
+        float_eq::assert_float_eq!(result.brightness, 0.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn approx_eq() {
+        let one = Hsv {
+            hue: 359.9,
+            saturation: 50.0,
+            brightness: 50.0,
+        };
+        let other = Hsv {
+            hue: 0.05,
+            saturation: 50.05,
+            brightness: 49.95,
+        };
+        assert!(one.approx_eq(&other, 0.2));
+        assert!(!one.approx_eq(&other, 0.01));
+        assert!(!one.approx_eq(
+            &Hsv {
+                brightness: f32::NAN,
+                ..one
+            },
+            0.2
+        ));
+    }
+}
+
 #[cfg(test)]
 mod interpolate_tests {
     use super::Hsv;
 
+    #[test]
+    fn hue_stays_below_360() {
+        let result =
+            Hsv::calculate_interpolated(&Hsv::from_hue(10.0), &Hsv::from_hue(350.0), 0.500_000_1);
+        assert!((0.0..360.0).contains(&result.hue), "{}", result.hue);
+        assert!(Hsv::new(result.hue, result.saturation, result.brightness).is_ok());
+    }
+
     #[test]
     fn before_zero() {
         let start = Hsv {
@@ -189,7 +574,137 @@ mod interpolate_tests {
     }
 }
 
-#[cfg(all(test, feature = "bracket-color"))]
+#[cfg(test)]
+mod interpolate_direction_tests {
+    use super::*;
+
+    #[test]
+    fn longest_goes_through_purple() {
+        let orange = Hsv::from_hue(30.0);
+        let yellow = Hsv::from_hue(60.0);
+        let result =
+            Hsv::calculate_interpolated_in_direction(&orange, &yellow, 0.5, HueDirection::Longest);
+        float_eq::assert_float_eq!(result.hue, 225.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn counter_clockwise() {
+        let result = Hsv::calculate_interpolated_in_direction(
+            &Hsv::from_hue(10.0),
+            &Hsv::from_hue(40.0),
+            0.5,
+            HueDirection::CounterClockwise,
+        );
+        float_eq::assert_float_eq!(result.hue, 205.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn full_cycle_rainbow() {
+        let red = Hsv::from_hue(0.0);
+        for (position, hue) in [(0.25, 90.0), (0.5, 180.0), (0.75, 270.0)] {
+            let result = Hsv::calculate_interpolated_in_direction(
+                &red,
+                &red,
+                position,
+                HueDirection::ClockwiseFullCycle,
+            );
+            float_eq::assert_float_eq!(result.hue, hue, abs <= 0.1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod approach_tests {
+    use super::*;
+
+    const BLACK: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 0.0,
+    };
+
+    #[test]
+    fn arrive_together() {
+        let start = Hsv {
+            hue: 0.0,
+            saturation: 90.0,
+            brightness: 50.0,
+        };
+        let target = Hsv {
+            hue: 100.0,
+            saturation: 100.0,
+            brightness: 30.0,
+        };
+        let result = start.approach(&target, 10.0);
+        float_eq::assert_float_eq!(result.hue, 10.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.saturation, 91.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.brightness, 48.0, abs <= 0.01);
+
+        let mut current = start;
+        for _ in 0..9 {
+            current = current.approach(&target, 10.0);
+            assert!(current.saturation < 100.0);
+        }
+        current = current.approach(&target, 10.0);
+        float_eq::assert_float_eq!(current.hue, 100.0, abs <= 0.01);
+        float_eq::assert_float_eq!(current.saturation, 100.0, abs <= 0.01);
+        float_eq::assert_float_eq!(current.brightness, 30.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn hue_over_zero() {
+        let start = Hsv::from_hue(350.0);
+        let result = start.approach(&Hsv::from_hue(20.0), 5.0);
+        float_eq::assert_float_eq!(result.hue, 355.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn within_step_is_target() {
+        let result = Hsv::from_hue(10.0).approach(&Hsv::from_hue(12.0), 5.0);
+        float_eq::assert_float_eq!(result.hue, 12.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn nan_is_target() {
+        let start = Hsv {
+            brightness: f32::NAN,
+            ..BLACK
+        };
+        let result = start.approach(&Hsv::from_hue(120.0), 1.0);
+        float_eq::assert_float_eq!(result.hue, 120.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn always_moves_hue_from_black() {
+        let result = BLACK.approach(&Hsv::from_hue(180.0), 10.0);
+        float_eq::assert_float_eq!(result.hue, 10.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn skip_achromatic_jumps_from_black() {
+        let result =
+            BLACK.approach_with_hue_mode(&Hsv::from_hue(180.0), 10.0, HueMode::SkipAchromatic);
+        float_eq::assert_float_eq!(result.hue, 180.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.saturation, 10.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.brightness, 10.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn skip_achromatic_keeps_hue_towards_white() {
+        let white = Hsv {
+            hue: 0.0,
+            saturation: 0.0,
+            brightness: 100.0,
+        };
+        let result =
+            Hsv::from_hue(180.0).approach_with_hue_mode(&white, 10.0, HueMode::SkipAchromatic);
+        float_eq::assert_float_eq!(result.hue, 180.0, abs <= 0.01);
+        float_eq::assert_float_eq!(result.saturation, 90.0, abs <= 0.01);
+    }
+}
+
+#[cfg(test)]
 mod to_rgb_tests {
     use super::*;
 
@@ -273,4 +788,33 @@ mod to_rgb_tests {
         assert_eq!(green, 0);
         assert_eq!(blue, 0);
     }
+
+    #[test]
+    fn hue_60_is_yellow() {
+        let (red, green, blue) = hsv_to_rgb(60.0, 100.0, 100.0);
+        assert_eq!(red, 255);
+        assert_eq!(green, 255);
+        assert_eq!(blue, 0);
+    }
+
+    #[test]
+    fn u16_full_range() {
+        let (red, green, blue) = Hsv::from_hue(180.0).to_rgb_u16();
+        assert_eq!(red, 0);
+        assert_eq!(green, u16::MAX);
+        assert_eq!(blue, u16::MAX);
+    }
+
+    #[test]
+    fn duty_with_gamma() {
+        let (red, green, blue) = Hsv {
+            hue: 0.0,
+            saturation: 50.0,
+            brightness: 100.0,
+        }
+        .to_rgb_duty(&BrightnessCurve::Gamma(2.0), PwmResolution::Bits12);
+        assert_eq!(red, 4095);
+        assert_eq!(green, 1024);
+        assert_eq!(blue, 1024);
+    }
 }
@@ -0,0 +1,182 @@
+use crate::angle_distance;
+use crate::light::normalize_hue;
+
+/// Direction to take around the hue circle.
+///
+/// Clockwise is the direction of increasing hue: red, yellow, green, blue, purple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HueDirection {
+    /// Shorter way around like [`angle_distance`]
+    Shortest,
+    /// Longer way around. Equal hues go the full cycle clockwise.
+    Longest,
+    /// Increasing hue
+    Clockwise,
+    /// Decreasing hue
+    CounterClockwise,
+    /// Like [`HueDirection::Clockwise`] but equal hues go the full cycle
+    ClockwiseFullCycle,
+    /// Like [`HueDirection::CounterClockwise`] but equal hues go the full cycle
+    CounterClockwiseFullCycle,
+}
+
+impl HueDirection {
+    /// Distance in degrees from start to end in this direction.
+    ///
+    /// Positive is clockwise, negative counter-clockwise.
+    #[must_use]
+    pub fn distance(self, start: f32, end: f32) -> f32 {
+        let clockwise = normalize_hue(end - start);
+        match self {
+            Self::Shortest => angle_distance(start, end),
+            Self::Longest => {
+                let shortest = angle_distance(start, end);
+                if shortest <= 0.0 {
+                    shortest + 360.0
+                } else {
+                    shortest - 360.0
+                }
+            }
+            Self::Clockwise => clockwise,
+            Self::CounterClockwise => {
+                if clockwise > 0.0 {
+                    clockwise - 360.0
+                } else {
+                    0.0
+                }
+            }
+            Self::ClockwiseFullCycle => {
+                if clockwise > 0.0 {
+                    clockwise
+                } else {
+                    360.0
+                }
+            }
+            Self::CounterClockwiseFullCycle => clockwise - 360.0,
+        }
+    }
+
+    /// Fixed direction which does not depend on start and end anymore.
+    ///
+    /// Stepwise approaches evaluate the direction again on every step.
+    /// [`HueDirection::Longest`] would turn around halfway then, so fix it at the start of the fade.
+    #[must_use]
+    pub fn fixed(self, start: f32, end: f32) -> Self {
+        let distance = self.distance(start, end);
+        if distance >= 360.0 {
+            Self::ClockwiseFullCycle
+        } else if distance <= -360.0 {
+            Self::CounterClockwiseFullCycle
+        } else if distance > 0.0 {
+            Self::Clockwise
+        } else if distance < 0.0 {
+            Self::CounterClockwise
+        } else {
+            Self::Shortest
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest() {
+        float_eq::assert_float_eq!(
+            HueDirection::Shortest.distance(350.0, 10.0),
+            20.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::Shortest.distance(10.0, 350.0),
+            -20.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn longest() {
+        float_eq::assert_float_eq!(
+            HueDirection::Longest.distance(350.0, 10.0),
+            -340.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::Longest.distance(10.0, 350.0),
+            340.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::Longest.distance(42.0, 42.0),
+            360.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn clockwise() {
+        float_eq::assert_float_eq!(
+            HueDirection::Clockwise.distance(350.0, 10.0),
+            20.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::Clockwise.distance(10.0, 350.0),
+            340.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::Clockwise.distance(42.0, 42.0),
+            0.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn counter_clockwise() {
+        let direction = HueDirection::CounterClockwise;
+        float_eq::assert_float_eq!(direction.distance(350.0, 10.0), -340.0, abs <= 0.01);
+        float_eq::assert_float_eq!(direction.distance(10.0, 350.0), -20.0, abs <= 0.01);
+        float_eq::assert_float_eq!(direction.distance(42.0, 42.0), 0.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn full_cycle() {
+        float_eq::assert_float_eq!(
+            HueDirection::ClockwiseFullCycle.distance(42.0, 42.0),
+            360.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::CounterClockwiseFullCycle.distance(42.0, 42.0),
+            -360.0,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(
+            HueDirection::ClockwiseFullCycle.distance(10.0, 20.0),
+            10.0,
+            abs <= 0.01
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        assert_eq!(
+            HueDirection::Longest.fixed(10.0, 20.0),
+            HueDirection::CounterClockwise
+        );
+        assert_eq!(
+            HueDirection::Shortest.fixed(10.0, 20.0),
+            HueDirection::Clockwise
+        );
+        assert_eq!(
+            HueDirection::ClockwiseFullCycle.fixed(10.0, 10.0),
+            HueDirection::ClockwiseFullCycle
+        );
+        assert_eq!(
+            HueDirection::ClockwiseFullCycle.fixed(10.0, 20.0),
+            HueDirection::Clockwise
+        );
+    }
+}
@@ -2,13 +2,41 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use self::angle_distance::angle_distance;
-pub use self::hsv::Hsv;
+pub use self::brightness_curve::{BrightnessCurve, PwmResolution};
+pub use self::color_temperature::ColorTemperature;
+pub use self::easing::Easing;
+pub use self::gradient::{Gradient, GradientStop};
+pub use self::group::{GroupState, GroupStrategy};
+pub use self::hsv::{Hsv, HsvError, HueMode};
+pub use self::hue_direction::HueDirection;
+pub use self::light_state::{LightState, PowerOnBehavior};
+pub use self::motion::{MotionLimits, MotionProfile};
+pub use self::oklab::{Oklab, Oklch};
+pub use self::rgb::Rgb;
+pub use self::rgbw::{Rgbw, Rgbww};
+pub use self::transition::Transition;
+pub use self::xy::{Gamut, Xy};
 
 mod angle_distance;
+mod brightness_curve;
+pub mod circular;
+mod color_temperature;
+mod easing;
+mod gradient;
+mod group;
 mod hsv;
+mod hue_direction;
 pub mod interpolate;
 pub mod light;
+mod light_state;
 #[cfg(feature = "location")]
 pub mod location;
+mod motion;
+mod oklab;
+mod rgb;
+mod rgbw;
+pub mod smoothing;
 #[cfg(feature = "chrono")]
 pub mod time;
+mod transition;
+mod xy;
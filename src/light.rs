@@ -1,24 +1,41 @@
-use crate::angle_distance::angle_distance;
+use core::ops::{Range, RangeInclusive};
+use core::time::Duration;
+
+use crate::HueDirection;
 
 /// `f32::abs` requires `std`
-fn abs(n: f32) -> f32 {
+pub(crate) fn abs(n: f32) -> f32 {
     if n.is_sign_positive() { n } else { -n }
 }
 
 /// use `rem_euclid` instead but it requires `std`
 pub(crate) fn normalize_hue(hue: f32) -> f32 {
-    let hue = hue % 360.0;
-    if hue.is_sign_negative() {
-        hue + 360.0
-    } else {
-        hue
-    }
+    wrap(hue, 360.0)
 }
 
+/// Approach the target by `step_size` within `0.0..=100.0`.
+///
+/// See [`approach_linear_in`] for the behavior of NaN and infinity.
 #[must_use]
 pub fn approach_linear(current: f32, target: f32, step_size: f32) -> f32 {
+    approach_linear_in(current, target, step_size, 0.0..=100.0)
+}
+
+/// Approach the target by `step_size` with every step clamped into the range.
+///
+/// The target is returned as is once it is within `step_size`, even when it is outside of the range.
+/// A NaN `current` also results in the target.
+/// An infinite `current` is clamped into the range.
+/// An empty range like `50.0..=20.0` or one with NaN bounds also results in the target.
+#[must_use]
+pub fn approach_linear_in(
+    current: f32,
+    target: f32,
+    step_size: f32,
+    range: RangeInclusive<f32>,
+) -> f32 {
     let distance = target - current;
-    if distance.is_nan() || abs(distance) <= step_size {
+    if range.is_empty() || distance.is_nan() || abs(distance) <= step_size {
         return target;
     }
 
@@ -27,7 +44,21 @@ pub fn approach_linear(current: f32, target: f32, step_size: f32) -> f32 {
     } else {
         current - step_size
     }
-    .clamp(0.0, 100.0)
+    .clamp(*range.start(), *range.end())
+}
+
+/// Approach the target with `rate_per_second` for the `elapsed` time since the last update.
+///
+/// The speed stays the same regardless of how often it is called.
+/// Works with the durations of the `time` module like `duration_until_next_full_second`.
+#[must_use]
+pub fn approach_linear_rate(
+    current: f32,
+    target: f32,
+    rate_per_second: f32,
+    elapsed: Duration,
+) -> f32 {
+    approach_linear(current, target, rate_per_second * elapsed.as_secs_f32())
 }
 
 #[test]
@@ -59,19 +90,96 @@ fn approach_linear_negative_infinity() {
     );
 }
 
+/// Approach the target hue by `step_size` taking the shorter way around.
+///
+/// See [`approach_cyclic`] for the behavior of NaN and infinity.
 #[must_use]
 pub fn approach_hue(current: f32, target: f32, step_size: f32) -> f32 {
-    let distance = angle_distance(current, target);
+    approach_cyclic(current, target, step_size, 0.0..360.0)
+}
+
+/// Approach the target hue by `step_size` in the given direction.
+///
+/// The direction is evaluated on every call.
+/// Use [`HueDirection::fixed`] at the start of the fade for [`HueDirection::Longest`] as it would turn around halfway otherwise.
+/// Full cycle directions start another cycle when called again after reaching the target.
+#[must_use]
+pub fn approach_hue_in_direction(
+    current: f32,
+    target: f32,
+    step_size: f32,
+    direction: HueDirection,
+) -> f32 {
+    let distance = direction.distance(current, target);
     if distance.is_nan() || abs(distance) <= step_size {
         return target;
     }
 
+    normalize_hue(if distance.is_sign_positive() {
+        current + step_size
+    } else {
+        current - step_size
+    })
+}
+
+/// Approach the target by `step_size` on a cyclic range where the end wraps around to the start.
+///
+/// Takes the shorter way around and the result is wrapped into the range.
+/// The target is returned as is once it is within `step_size`.
+/// A NaN or infinite `current` results in the target.
+/// An empty range like `10.0..0.0` or one with NaN bounds also results in the target.
+#[must_use]
+pub fn approach_cyclic(current: f32, target: f32, step_size: f32, range: Range<f32>) -> f32 {
+    let period = range.end - range.start;
+    let distance = cyclic_distance(current, target, period);
+    if range.is_empty() || distance.is_nan() || abs(distance) <= step_size {
+        return target;
+    }
+
     let next = if distance.is_sign_positive() {
         current + step_size
     } else {
         current - step_size
     };
-    normalize_hue(next)
+    range.start + wrap(next - range.start, period)
+}
+
+/// Generalized `angle_distance` for any period
+fn cyclic_distance(start: f32, end: f32, period: f32) -> f32 {
+    let half = period / 2.0;
+    let difference = (end - start) % period;
+    if difference < -half {
+        difference + period
+    } else if difference > half {
+        difference - period
+    } else {
+        difference
+    }
+}
+
+/// Wraps into `0.0..period`, see [`normalize_hue`]
+fn wrap(value: f32, period: f32) -> f32 {
+    let value = value % period;
+    let value = if value.is_sign_negative() {
+        value + period
+    } else {
+        value
+    };
+    // Tiny negative values like -0.0 round up to exactly the period
+    if value >= period { 0.0 } else { value }
+}
+
+/// Approach the target hue with `rate_per_second` for the `elapsed` time since the last update.
+///
+/// See [`approach_linear_rate`].
+#[must_use]
+pub fn approach_hue_rate(
+    current: f32,
+    target: f32,
+    rate_per_second: f32,
+    elapsed: Duration,
+) -> f32 {
+    approach_hue(current, target, rate_per_second * elapsed.as_secs_f32())
 }
 
 #[test]
@@ -104,7 +212,217 @@ fn approach_hue_negative_infinity() {
     float_eq::assert_float_eq!(approach_hue(f32::NEG_INFINITY, 90.0, 1.0), 90.0, abs <= 0.1);
 }
 
+#[test]
+fn approach_hue_stays_below_360() {
+    let result = approach_hue(0.000_01, 300.0, 0.000_02);
+    assert!((0.0..360.0).contains(&result), "{result}");
+}
+
 #[test]
 fn approach_hue_nan() {
     float_eq::assert_float_eq!(approach_hue(f32::NAN, 90.0, 1.0), 90.0, abs <= 0.1);
 }
+
+#[test]
+fn approach_linear_in_mired() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(200.0, 500.0, 10.0, 153.0..=500.0),
+        210.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_in_zigbee_level() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(250.0, 254.0, 10.0, 0.0..=254.0),
+        254.0,
+        abs <= 0.1
+    );
+    float_eq::assert_float_eq!(
+        approach_linear_in(150.0, 254.0, 10.0, 0.0..=254.0),
+        160.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_in_infinity() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(f32::INFINITY, 200.0, 10.0, 153.0..=500.0),
+        500.0,
+        abs <= 0.1
+    );
+    float_eq::assert_float_eq!(
+        approach_linear_in(f32::NEG_INFINITY, 200.0, 10.0, 153.0..=500.0),
+        153.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_in_target_outside() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(400.0, 600.0, 10.0, 153.0..=500.0),
+        410.0,
+        abs <= 0.1
+    );
+    float_eq::assert_float_eq!(
+        approach_linear_in(595.0, 600.0, 10.0, 153.0..=500.0),
+        600.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_in_empty_range() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(30.0, 40.0, 1.0, 50.0..=20.0),
+        40.0,
+        abs <= 0.1
+    );
+    float_eq::assert_float_eq!(
+        approach_linear_in(30.0, 40.0, 1.0, f32::NAN..=20.0),
+        40.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_in_nan() {
+    float_eq::assert_float_eq!(
+        approach_linear_in(f32::NAN, 200.0, 10.0, 153.0..=500.0),
+        200.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_cyclic_over_end() {
+    float_eq::assert_float_eq!(
+        approach_cyclic(178.0, -170.0, 5.0, -180.0..180.0),
+        -177.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_cyclic_over_start() {
+    float_eq::assert_float_eq!(
+        approach_cyclic(-178.0, 170.0, 5.0, -180.0..180.0),
+        177.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_cyclic_minutes() {
+    float_eq::assert_float_eq!(approach_cyclic(58.0, 3.0, 1.0, 0.0..60.0), 59.0, abs <= 0.1);
+    float_eq::assert_float_eq!(approach_cyclic(59.5, 3.0, 1.0, 0.0..60.0), 0.5, abs <= 0.1);
+}
+
+#[test]
+fn approach_cyclic_empty_range() {
+    float_eq::assert_float_eq!(approach_cyclic(2.0, 8.0, 1.0, 10.0..0.0), 8.0, abs <= 0.01);
+    float_eq::assert_float_eq!(approach_cyclic(2.0, 8.0, 1.0, 5.0..5.0), 8.0, abs <= 0.01);
+}
+
+#[test]
+fn approach_cyclic_infinity() {
+    float_eq::assert_float_eq!(
+        approach_cyclic(f32::INFINITY, 10.0, 1.0, 0.0..60.0),
+        10.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_rate_per_second() {
+    float_eq::assert_float_eq!(
+        approach_linear_rate(50.0, 100.0, 10.0, Duration::from_millis(500)),
+        55.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_linear_rate_independent_of_ticks() {
+    let mut ticked = 0.0;
+    for millis in [50, 150, 100, 300, 400] {
+        ticked = approach_linear_rate(ticked, 100.0, 20.0, Duration::from_millis(millis));
+    }
+    let once = approach_linear_rate(0.0, 100.0, 20.0, Duration::from_secs(1));
+    float_eq::assert_float_eq!(ticked, once, abs <= 0.01);
+    float_eq::assert_float_eq!(once, 20.0, abs <= 0.01);
+}
+
+#[test]
+fn approach_linear_rate_no_time_passed() {
+    float_eq::assert_float_eq!(
+        approach_linear_rate(50.0, 100.0, 10.0, Duration::ZERO),
+        50.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_hue_rate_over_zero() {
+    float_eq::assert_float_eq!(
+        approach_hue_rate(355.0, 20.0, 20.0, Duration::from_millis(500)),
+        5.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_hue_in_direction_shortest() {
+    float_eq::assert_float_eq!(
+        approach_hue_in_direction(355.0, 10.0, 10.0, HueDirection::Shortest),
+        5.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_hue_in_direction_counter_clockwise() {
+    float_eq::assert_float_eq!(
+        approach_hue_in_direction(355.0, 10.0, 10.0, HueDirection::CounterClockwise),
+        345.0,
+        abs <= 0.1
+    );
+}
+
+#[test]
+fn approach_hue_in_direction_fixed_longest_goes_all_the_way() {
+    let direction = HueDirection::Longest.fixed(10.0, 20.0);
+    let mut current = 10.0;
+    for _ in 0..34 {
+        current = approach_hue_in_direction(current, 20.0, 10.0, direction);
+        assert!(
+            !(10.0..=20.0).contains(&current),
+            "{current} took the short way"
+        );
+    }
+    current = approach_hue_in_direction(current, 20.0, 10.0, direction);
+    float_eq::assert_float_eq!(current, 20.0, abs <= 0.1);
+}
+
+#[test]
+fn approach_hue_in_direction_full_cycle() {
+    let mut current = 90.0;
+    for _ in 0..35 {
+        current = approach_hue_in_direction(current, 90.0, 10.0, HueDirection::ClockwiseFullCycle);
+        assert!(!(89.9..=90.0).contains(&current), "{current} stopped early");
+    }
+    current = approach_hue_in_direction(current, 90.0, 10.0, HueDirection::ClockwiseFullCycle);
+    float_eq::assert_float_eq!(current, 90.0, abs <= 0.1);
+}
+
+#[test]
+fn approach_hue_in_direction_nan() {
+    float_eq::assert_float_eq!(
+        approach_hue_in_direction(f32::NAN, 10.0, 1.0, HueDirection::Clockwise),
+        10.0,
+        abs <= 0.1
+    );
+}
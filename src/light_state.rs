@@ -0,0 +1,336 @@
+use crate::Hsv;
+use crate::light::approach_linear_in;
+
+/// Color used when turning on a [`LightState`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerOnBehavior {
+    /// The color before turning off
+    Last,
+    /// White at full brightness like most bulbs after a power loss
+    Default,
+    Fixed(Hsv),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimming {
+    Stopped,
+    Up,
+    Down,
+}
+
+/// On / off state of a light which remembers its color while off.
+///
+/// Relative dimming works like KNX: [`LightState::dim_up`] or [`LightState::dim_down`] start it,
+/// [`LightState::tick`] moves the brightness and [`LightState::stop`] ends it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightState {
+    /// Remembered color, also while off
+    pub color: Hsv,
+    pub on: bool,
+    /// Lowest brightness while on so that the light is always visible
+    pub min_brightness: f32,
+    /// Highest brightness while on.
+    ///
+    /// A maximum below the minimum is treated as if both were swapped.
+    pub max_brightness: f32,
+    pub power_on: PowerOnBehavior,
+    dimming: Dimming,
+}
+
+impl LightState {
+    const WHITE: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    };
+
+    /// Turned off light which turns on with the given color
+    #[must_use]
+    pub const fn new(color: Hsv) -> Self {
+        Self {
+            color,
+            on: false,
+            min_brightness: 1.0,
+            max_brightness: 100.0,
+            power_on: PowerOnBehavior::Last,
+            dimming: Dimming::Stopped,
+        }
+    }
+
+    /// Color to show, which has no brightness while off
+    #[must_use]
+    pub const fn output(&self) -> Hsv {
+        if self.on {
+            self.color
+        } else {
+            Hsv {
+                brightness: 0.0,
+                ..self.color
+            }
+        }
+    }
+
+    #[must_use]
+    pub const fn is_dimming(&self) -> bool {
+        !matches!(self.dimming, Dimming::Stopped)
+    }
+
+    pub const fn turn_on(&mut self) {
+        if self.on {
+            return;
+        }
+        self.apply_power_on();
+        self.color.brightness = self.clamp_brightness(self.color.brightness);
+        self.on = true;
+    }
+
+    /// Turns off and remembers the color for the next [`LightState::turn_on`]
+    pub const fn turn_off(&mut self) {
+        self.on = false;
+        self.dimming = Dimming::Stopped;
+    }
+
+    pub const fn toggle(&mut self) {
+        if self.on {
+            self.turn_off();
+        } else {
+            self.turn_on();
+        }
+    }
+
+    /// Sets the color and turns on.
+    ///
+    /// A brightness of zero or below turns off instead and keeps the remembered color.
+    pub const fn set_color(&mut self, color: Hsv) {
+        if color.brightness <= 0.0 {
+            self.turn_off();
+            return;
+        }
+        self.color = Hsv {
+            brightness: self.clamp_brightness(color.brightness),
+            ..color
+        };
+        self.on = true;
+    }
+
+    /// Starts dimming up.
+    ///
+    /// A light which is off turns on with the [`PowerOnBehavior`] color at the minimum brightness.
+    pub const fn dim_up(&mut self) {
+        if !self.on {
+            self.apply_power_on();
+            self.color.brightness = self.brightness_limits().0;
+            self.on = true;
+        }
+        self.dimming = Dimming::Up;
+    }
+
+    /// Starts dimming down to the minimum brightness. The light stays on.
+    pub const fn dim_down(&mut self) {
+        if self.on {
+            self.dimming = Dimming::Down;
+        }
+    }
+
+    /// Ends the dimming session at the current brightness
+    pub const fn stop(&mut self) {
+        self.dimming = Dimming::Stopped;
+    }
+
+    /// Moves the brightness of a dimming session by `step_size` and returns the output.
+    ///
+    /// The session ends on its own when the minimum or maximum is reached.
+    pub fn tick(&mut self, step_size: f32) -> Hsv {
+        let (min, max) = self.brightness_limits();
+        let target = match self.dimming {
+            Dimming::Stopped => return self.output(),
+            Dimming::Up => max,
+            Dimming::Down => min,
+        };
+        let range = min..=max;
+        let brightness = approach_linear_in(self.color.brightness, target, step_size, range);
+        self.color.brightness = brightness;
+        #[expect(clippy::float_cmp, reason = "approach returns the target exactly")]
+        if brightness == target {
+            self.stop();
+        }
+        self.output()
+    }
+
+    /// Color to turn on with
+    const fn apply_power_on(&mut self) {
+        match self.power_on {
+            PowerOnBehavior::Last => {}
+            PowerOnBehavior::Default => self.color = Self::WHITE,
+            PowerOnBehavior::Fixed(color) => self.color = color,
+        }
+    }
+
+    /// Minimum and maximum brightness in the right order
+    const fn brightness_limits(&self) -> (f32, f32) {
+        (
+            self.min_brightness.min(self.max_brightness),
+            self.min_brightness.max(self.max_brightness),
+        )
+    }
+
+    const fn clamp_brightness(&self, brightness: f32) -> f32 {
+        let (min, max) = self.brightness_limits();
+        brightness.max(min).min(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF_RED: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 100.0,
+        brightness: 50.0,
+    };
+
+    #[test]
+    fn starts_off() {
+        let light = LightState::new(HALF_RED);
+        assert!(!light.on);
+        float_eq::assert_float_eq!(light.output().brightness, 0.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn toggle_restores_brightness() {
+        let mut light = LightState::new(HALF_RED);
+        light.toggle();
+        float_eq::assert_float_eq!(light.output().brightness, 50.0, abs <= 0.01);
+        light.toggle();
+        float_eq::assert_float_eq!(light.output().brightness, 0.0, abs <= 0.01);
+        float_eq::assert_float_eq!(light.color.brightness, 50.0, abs <= 0.01);
+        light.toggle();
+        float_eq::assert_float_eq!(light.output().brightness, 50.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn power_on_default() {
+        let mut light = LightState::new(HALF_RED);
+        light.power_on = PowerOnBehavior::Default;
+        light.turn_on();
+        float_eq::assert_float_eq!(light.output().saturation, 0.0, abs <= 0.01);
+        float_eq::assert_float_eq!(light.output().brightness, 100.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn power_on_fixed() {
+        let mut light = LightState::new(HALF_RED);
+        light.power_on = PowerOnBehavior::Fixed(Hsv::from_hue(120.0));
+        light.turn_on();
+        float_eq::assert_float_eq!(light.output().hue, 120.0, abs <= 0.01);
+        float_eq::assert_float_eq!(light.output().brightness, 100.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn power_on_respects_min_brightness() {
+        let mut light = LightState::new(Hsv {
+            brightness: 0.5,
+            ..HALF_RED
+        });
+        light.min_brightness = 5.0;
+        light.turn_on();
+        float_eq::assert_float_eq!(light.output().brightness, 5.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn set_color_zero_turns_off_and_remembers() {
+        let mut light = LightState::new(HALF_RED);
+        light.turn_on();
+        light.set_color(Hsv {
+            brightness: 0.0,
+            ..HALF_RED
+        });
+        assert!(!light.on);
+        light.turn_on();
+        float_eq::assert_float_eq!(light.output().brightness, 50.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn dim_up_from_off_starts_at_min() {
+        let mut light = LightState::new(HALF_RED);
+        light.min_brightness = 10.0;
+        light.dim_up();
+        assert!(light.on);
+        assert!(light.is_dimming());
+        float_eq::assert_float_eq!(light.output().brightness, 10.0, abs <= 0.01);
+        float_eq::assert_float_eq!(light.tick(5.0).brightness, 15.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn dim_up_from_off_uses_power_on() {
+        let mut light = LightState::new(HALF_RED);
+        light.power_on = PowerOnBehavior::Fixed(Hsv::from_hue(120.0));
+        light.min_brightness = 10.0;
+        light.dim_up();
+        float_eq::assert_float_eq!(light.output().hue, 120.0, abs <= 0.01);
+        float_eq::assert_float_eq!(light.output().brightness, 10.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn dim_down_stops_at_min_and_stays_on() {
+        let mut light = LightState::new(HALF_RED);
+        light.min_brightness = 10.0;
+        light.turn_on();
+        light.dim_down();
+        for _ in 0..100 {
+            light.tick(5.0);
+        }
+        assert!(light.on);
+        assert!(!light.is_dimming());
+        float_eq::assert_float_eq!(light.output().brightness, 10.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn dim_up_stops_at_max() {
+        let mut light = LightState::new(HALF_RED);
+        light.max_brightness = 80.0;
+        light.turn_on();
+        light.dim_up();
+        for _ in 0..100 {
+            light.tick(5.0);
+        }
+        assert!(!light.is_dimming());
+        float_eq::assert_float_eq!(light.output().brightness, 80.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn stop_keeps_brightness() {
+        let mut light = LightState::new(HALF_RED);
+        light.turn_on();
+        light.dim_up();
+        light.tick(5.0);
+        light.stop();
+        float_eq::assert_float_eq!(light.tick(5.0).brightness, 55.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn swapped_limits() {
+        let mut light = LightState::new(HALF_RED);
+        light.min_brightness = 50.0;
+        light.max_brightness = 20.0;
+        light.turn_on();
+        float_eq::assert_float_eq!(light.output().brightness, 50.0, abs <= 0.01);
+        light.set_color(Hsv::from_hue(120.0));
+        float_eq::assert_float_eq!(light.output().brightness, 50.0, abs <= 0.01);
+        light.dim_down();
+        for _ in 0..100 {
+            light.tick(5.0);
+        }
+        assert!(!light.is_dimming());
+        float_eq::assert_float_eq!(light.output().brightness, 20.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn dim_down_while_off_does_nothing() {
+        let mut light = LightState::new(HALF_RED);
+        light.dim_down();
+        assert!(!light.is_dimming());
+        float_eq::assert_float_eq!(light.tick(5.0).brightness, 0.0, abs <= 0.01);
+    }
+}
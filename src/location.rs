@@ -1,63 +1,332 @@
+use core::time::Duration;
+
 use chrono::{DateTime, TimeZone};
 
+/// Position of the sun in the sky in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SunPosition {
+    /// Compass direction clockwise from north (0.0) over east (90.0) to 360.0
+    pub azimuth: f64,
+    /// Angle above the horizon, negative when the sun is below
+    pub elevation: f64,
+}
+
+#[must_use]
+pub fn sun_position<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    latitude: f64,
+    longitude: f64,
+) -> SunPosition {
+    let timestamp = suncalc::Timestamp(datetime.timestamp_millis());
+    let position = suncalc::get_position(timestamp, latitude, longitude);
+    // suncalc measures the azimuth from south towards west
+    let azimuth = (position.azimuth.to_degrees() + 180.0) % 360.0;
+    SunPosition {
+        azimuth: if azimuth < 0.0 {
+            azimuth + 360.0
+        } else {
+            azimuth
+        },
+        elevation: position.altitude.to_degrees(),
+    }
+}
+
+/// Events of the sun around the day of a date.
+///
+/// Events which do not happen on that day like astronomical dusk during summer nights far north are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolarEvents<Tz: TimeZone> {
+    /// Sun at -18°, end of the night
+    pub astronomical_dawn: Option<DateTime<Tz>>,
+    /// Sun at -12°
+    pub nautical_dawn: Option<DateTime<Tz>>,
+    /// Sun at -6°, also the begin of the morning blue hour
+    pub civil_dawn: Option<DateTime<Tz>>,
+    /// Sun at -4°
+    pub morning_blue_hour_end: Option<DateTime<Tz>>,
+    /// Top edge of the sun appears on the horizon
+    pub sunrise_begin: Option<DateTime<Tz>>,
+    /// Bottom edge of the sun touches the horizon
+    pub sunrise_end: Option<DateTime<Tz>>,
+    /// Sun at 6°
+    pub morning_golden_hour_end: Option<DateTime<Tz>>,
+    /// Sun at its highest
+    pub solar_noon: Option<DateTime<Tz>>,
+    /// Sun at 6°
+    pub evening_golden_hour_begin: Option<DateTime<Tz>>,
+    /// Bottom edge of the sun touches the horizon
+    pub sunset_begin: Option<DateTime<Tz>>,
+    /// Sun disappeared below the horizon
+    pub sunset_end: Option<DateTime<Tz>>,
+    /// Sun at -4°
+    pub evening_blue_hour_begin: Option<DateTime<Tz>>,
+    /// Sun at -6°, also the end of the evening blue hour
+    pub civil_dusk: Option<DateTime<Tz>>,
+    /// Sun at -12°
+    pub nautical_dusk: Option<DateTime<Tz>>,
+    /// Sun at -18°, begin of the night
+    pub astronomical_dusk: Option<DateTime<Tz>>,
+    /// Sun at its lowest
+    pub nadir: Option<DateTime<Tz>>,
+}
+
+/// Center of the sun below the horizon when the top edge appears, including refraction
+const SUNRISE: f64 = -0.833;
+
+/// suncalc returns zero for events which do not happen
+fn to_datetime<Tz: TimeZone>(timezone: &Tz, timestamp: suncalc::Timestamp) -> Option<DateTime<Tz>> {
+    if timestamp.0 == 0 {
+        return None;
+    }
+    timezone.timestamp_millis_opt(timestamp.0).single()
+}
+
+/// Finds when the sun crosses the elevation between two timestamps in milliseconds.
+///
+/// The elevation has to be only increasing or only decreasing in between.
+fn elevation_crossing(
+    start: i64,
+    end: i64,
+    elevation: f64,
+    latitude: f64,
+    longitude: f64,
+) -> Option<i64> {
+    let above = |timestamp: i64| {
+        let position = suncalc::get_position(suncalc::Timestamp(timestamp), latitude, longitude);
+        position.altitude.to_degrees() > elevation
+    };
+    let start_above = above(start);
+    if start_above == above(end) {
+        return None;
+    }
+    let mut lower = start;
+    let mut upper = end;
+    while upper - lower > 1000 {
+        let middle = lower + (upper - lower) / 2;
+        if above(middle) == start_above {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    Some(lower + (upper - lower) / 2)
+}
+
+#[must_use]
+pub fn get_solar_events<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+) -> SolarEvents<Tz> {
+    /// Blue hour is between -6° (civil twilight) and -4°
+    const BLUE_HOUR: f64 = -4.0;
+    const HALF_DAY: i64 = 12 * 60 * 60 * 1000;
+
+    let timestamp = datetime.timestamp_millis();
+    let times = suncalc::get_times(suncalc::Timestamp(timestamp), latitude, longitude, height);
+    let timezone = datetime.timezone();
+    let convert = |timestamp: suncalc::Timestamp| to_datetime(&timezone, timestamp);
+    let blue_hour = |start: i64, end: i64| {
+        elevation_crossing(start, end, BLUE_HOUR, latitude, longitude)
+            .and_then(|timestamp| convert(suncalc::Timestamp(timestamp)))
+    };
+
+    let noon = times.solar_noon.0;
+    SolarEvents {
+        astronomical_dawn: convert(times.night_end),
+        nautical_dawn: convert(times.nautical_dawn),
+        civil_dawn: convert(times.dawn),
+        morning_blue_hour_end: blue_hour(noon - HALF_DAY, noon),
+        sunrise_begin: convert(times.sunrise),
+        sunrise_end: convert(times.sunrise_end),
+        morning_golden_hour_end: convert(times.golden_hour_end),
+        solar_noon: convert(times.solar_noon),
+        evening_golden_hour_begin: convert(times.golden_hour),
+        sunset_begin: convert(times.sunset_start),
+        sunset_end: convert(times.sunset),
+        evening_blue_hour_begin: blue_hour(noon, noon + HALF_DAY),
+        civil_dusk: convert(times.dusk),
+        nautical_dusk: convert(times.nautical_dusk),
+        astronomical_dusk: convert(times.night),
+        nadir: convert(times.nadir),
+    }
+}
+
+/// Result of [`get_sunrise_sunset`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SunriseSunset<Tz: TimeZone> {
+    Normal {
+        sunrise: DateTime<Tz>,
+        sunset: DateTime<Tz>,
+    },
+    /// Above the polar circle the sun does not set on this day
+    PolarDay,
+    /// Above the polar circle the sun does not rise on this day
+    PolarNight,
+    /// The times do not exist unambiguously in the timezone
+    ConversionError,
+}
+
+/// Whether the sun is above the elevation at the given time.
+///
+/// At solar noon this tells whether it stays above all day when it does not cross the elevation.
+/// At nadir it tells whether it stays above all day at all.
+fn stays_above(
+    timestamp: suncalc::Timestamp,
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+) -> bool {
+    let position = suncalc::get_position(timestamp, latitude, longitude);
+    position.altitude.to_degrees() > elevation
+}
+
 pub fn get_sunrise_sunset<Tz: TimeZone>(
     datetime: &DateTime<Tz>,
     latitude: f64,
     longitude: f64,
     height: Option<f64>,
-) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+) -> SunriseSunset<Tz> {
     let timestamp = datetime.timestamp_millis();
     let times = suncalc::get_times(suncalc::Timestamp(timestamp), latitude, longitude, height);
-    let sunrise = times.sunrise.0;
-    let sunset = times.sunset.0;
-    if sunrise == 0 || sunset == 0 {
-        // Above polar circle.
-        return None;
+    if times.sunrise.0 == 0 || times.sunset.0 == 0 {
+        return if stays_above(times.solar_noon, latitude, longitude, SUNRISE) {
+            SunriseSunset::PolarDay
+        } else {
+            SunriseSunset::PolarNight
+        };
+    }
+    let timezone = datetime.timezone();
+    match (
+        to_datetime(&timezone, times.sunrise),
+        to_datetime(&timezone, times.sunset),
+    ) {
+        (Some(sunrise), Some(sunset)) => SunriseSunset::Normal { sunrise, sunset },
+        _ => SunriseSunset::ConversionError,
     }
-    let sunrise = datetime.timezone().timestamp_millis_opt(sunrise).single()?;
-    let sunset = datetime.timezone().timestamp_millis_opt(sunset).single()?;
-    Some((sunrise, sunset))
 }
 
-/// Calculate the relative brightness of the given `NaiveDateTime` between 0.0 and 1.0
+/// Shape of the brightness over the day used by [`calc_relative_brightness_with_curve`]
+#[derive(Debug, Clone, Copy)]
+pub enum DaylightCurve {
+    /// `1 - d^exponent` with `d` being the relative distance from solar noon (0.0) to the anchors (1.0).
+    /// Needs to be positive, higher values keep the brightness high for longer.
+    /// The result is clamped to 0.0 to 1.0 so other exponents result in 0.0.
+    Exponent(f32),
+    /// Sine of the sun elevation which only reaches 1.0 with the sun straight above
+    SunElevation,
+    /// Full brightness between sunrise and sunset with linear ramps from and to the anchors
+    Plateau,
+    /// Gets the relative distance from solar noon (0.0) to the anchors (1.0).
+    /// The result is clamped to 0.0 to 1.0.
+    Custom(fn(f32) -> f32),
+}
+
+/// Begin and end of the brightness used by [`calc_relative_brightness_with_curve`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaylightAnchors {
+    /// Civil twilight, sun at -6°
+    DawnToDusk,
+    SunriseToSunset,
+}
+
+/// Calculate the relative brightness of the given `DateTime` between 0.0 and 1.0
 /// # Panics
 /// Panics when the calculation result is not between 0.0 and 1.0 which indicates a code error.
-#[expect(clippy::cast_precision_loss)]
 #[must_use]
 pub fn calc_relative_brightness_of_time<Tz: TimeZone>(
     datetime: &DateTime<Tz>,
     latitude: f64,
     longitude: f64,
     height: Option<f64>,
+) -> f32 {
+    calc_relative_brightness_with_curve(
+        datetime,
+        latitude,
+        longitude,
+        height,
+        DaylightCurve::Exponent(3.0),
+        DaylightAnchors::DawnToDusk,
+    )
+}
+
+/// Calculate the relative brightness of the given `DateTime` between 0.0 and 1.0.
+///
+/// Outside of the anchors the brightness is 0.0.
+/// White nights where the sun sets but does not reach the anchors use the nadirs instead.
+///
+/// On polar days the brightness is 1.0, or the sine of the elevation for [`DaylightCurve::SunElevation`].
+/// On polar nights of the anchors it is 0.0.
+/// This is the same classification as [`get_sunrise_sunset`] but only the brightness is returned
+/// as a light does not need to behave differently on a polar day than at noon.
+/// Use [`get_sunrise_sunset`] when the polar state itself matters.
+/// # Panics
+/// Panics when the calculation result is not between 0.0 and 1.0 which indicates a code error.
+#[must_use]
+pub fn calc_relative_brightness_with_curve<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+    curve: DaylightCurve,
+    anchors: DaylightAnchors,
 ) -> f32 {
     let timestamp = datetime.timestamp_millis();
     let times = suncalc::get_times(suncalc::Timestamp(timestamp), latitude, longitude, height);
 
-    // let pos = suncalc::get_position(suncalc::Timestamp(timestamp), latitude, longitude);
-    // dbg!(&pos.altitude);
-
-    // dbg!(chrono::Local.timestamp_millis_opt(times.dawn.0));
-    // dbg!(chrono::Local.timestamp_millis_opt(times.solar_noon.0));
-    // dbg!(chrono::Local.timestamp_millis_opt(times.dusk.0));
-
-    let begin = times.dawn.0;
-    let end = times.dusk.0;
+    let (begin, end, elevation) = match anchors {
+        DaylightAnchors::DawnToDusk => (times.dawn.0, times.dusk.0, -6.0),
+        DaylightAnchors::SunriseToSunset => (times.sunrise.0, times.sunset.0, SUNRISE),
+    };
     let noon = times.solar_noon.0;
+    let nadir = times.nadir.0;
 
     if begin == 0 || end == 0 {
-        // Above polar circle. Time of date is irrelevant as its either 24h or 0h
-        let pos = suncalc::get_position(suncalc::Timestamp(timestamp), latitude, longitude);
-        return if pos.altitude > 0.0 { 1.0 } else { 0.0 };
+        if !stays_above(times.solar_noon, latitude, longitude, elevation) {
+            // Polar night of the anchors
+            return 0.0;
+        }
+        if stays_above(times.nadir, latitude, longitude, SUNRISE) {
+            // Polar day. Time of date is irrelevant as the sun does not set
+            return match curve {
+                DaylightCurve::SunElevation => elevation_sine(datetime, latitude, longitude),
+                _ => 1.0,
+            };
+        }
     }
+    // White night: the sun sets but does not reach the anchors
+    let begin = if begin == 0 { nadir } else { begin };
+    let end = if end == 0 { noon + (noon - nadir) } else { end };
 
     if timestamp < begin || timestamp > end {
         return 0.0;
     }
 
-    let max_distance = (noon - begin) as f32;
-    let current_distance = (noon - timestamp).abs() as f32;
-    let relative_distance = current_distance / max_distance;
-    let brightness_factor = 1.0 - (relative_distance.powi(3));
+    let brightness_factor = match curve {
+        DaylightCurve::Exponent(exponent) => {
+            clamp_factor(1.0 - libm::powf(relative_distance(timestamp, begin, noon, end), exponent))
+        }
+        DaylightCurve::SunElevation => elevation_sine(datetime, latitude, longitude),
+        DaylightCurve::Plateau => {
+            // The sun might not rise while there is twilight
+            let (sunrise, sunset) = if times.sunrise.0 == 0 || times.sunset.0 == 0 {
+                (noon, noon)
+            } else {
+                (times.sunrise.0.max(begin), times.sunset.0.min(end))
+            };
+            if timestamp < sunrise {
+                ratio(timestamp - begin, sunrise - begin)
+            } else if timestamp > sunset {
+                ratio(end - timestamp, end - sunset)
+            } else {
+                1.0
+            }
+        }
+        DaylightCurve::Custom(function) => {
+            clamp_factor(function(relative_distance(timestamp, begin, noon, end)))
+        }
+    };
     assert!(
         (0.0..=1.0).contains(&brightness_factor),
         "brightness_factor is not between 0.0 and 1.0: {brightness_factor}",
@@ -65,12 +334,174 @@ pub fn calc_relative_brightness_of_time<Tz: TimeZone>(
     brightness_factor
 }
 
+/// Distance from solar noon (0.0) to the begin or end (1.0)
+fn relative_distance(timestamp: i64, begin: i64, noon: i64, end: i64) -> f32 {
+    if timestamp < noon {
+        ratio(noon - timestamp, noon - begin)
+    } else {
+        ratio(timestamp - noon, end - noon)
+    }
+}
+
+/// Clamps to 0.0 to 1.0 with NaN being 0.0
+const fn clamp_factor(brightness: f32) -> f32 {
+    if brightness.is_nan() {
+        0.0
+    } else {
+        brightness.clamp(0.0, 1.0)
+    }
+}
+
+/// Ratio of the milliseconds clamped to 0.0 to 1.0
+#[expect(clippy::cast_precision_loss)]
+fn ratio(part: i64, whole: i64) -> f32 {
+    if whole <= 0 {
+        return 1.0;
+    }
+    (part as f32 / whole as f32).clamp(0.0, 1.0)
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn elevation_sine<Tz: TimeZone>(datetime: &DateTime<Tz>, latitude: f64, longitude: f64) -> f32 {
+    let elevation = sun_position(datetime, latitude, longitude).elevation;
+    (libm::sin(elevation.to_radians()) as f32).max(0.0)
+}
+
+/// One of the [`SolarEvents`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolarEvent {
+    AstronomicalDawn,
+    NauticalDawn,
+    CivilDawn,
+    MorningBlueHourEnd,
+    SunriseBegin,
+    SunriseEnd,
+    MorningGoldenHourEnd,
+    SolarNoon,
+    EveningGoldenHourBegin,
+    SunsetBegin,
+    SunsetEnd,
+    EveningBlueHourBegin,
+    CivilDusk,
+    NauticalDusk,
+    AstronomicalDusk,
+    Nadir,
+}
+
+impl<Tz: TimeZone> SolarEvents<Tz> {
+    #[must_use]
+    pub const fn get(&self, event: SolarEvent) -> Option<&DateTime<Tz>> {
+        match event {
+            SolarEvent::AstronomicalDawn => self.astronomical_dawn.as_ref(),
+            SolarEvent::NauticalDawn => self.nautical_dawn.as_ref(),
+            SolarEvent::CivilDawn => self.civil_dawn.as_ref(),
+            SolarEvent::MorningBlueHourEnd => self.morning_blue_hour_end.as_ref(),
+            SolarEvent::SunriseBegin => self.sunrise_begin.as_ref(),
+            SolarEvent::SunriseEnd => self.sunrise_end.as_ref(),
+            SolarEvent::MorningGoldenHourEnd => self.morning_golden_hour_end.as_ref(),
+            SolarEvent::SolarNoon => self.solar_noon.as_ref(),
+            SolarEvent::EveningGoldenHourBegin => self.evening_golden_hour_begin.as_ref(),
+            SolarEvent::SunsetBegin => self.sunset_begin.as_ref(),
+            SolarEvent::SunsetEnd => self.sunset_end.as_ref(),
+            SolarEvent::EveningBlueHourBegin => self.evening_blue_hour_begin.as_ref(),
+            SolarEvent::CivilDusk => self.civil_dusk.as_ref(),
+            SolarEvent::NauticalDusk => self.nautical_dusk.as_ref(),
+            SolarEvent::AstronomicalDusk => self.astronomical_dusk.as_ref(),
+            SolarEvent::Nadir => self.nadir.as_ref(),
+        }
+    }
+}
+
+/// Event on the day `days` away from `datetime`
+fn solar_event_on_day<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    days: i64,
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+) -> Option<DateTime<Tz>> {
+    let day = datetime
+        .clone()
+        .checked_add_signed(chrono::Duration::try_days(days)?)?;
+    get_solar_events(&day, latitude, longitude, height)
+        .get(event)
+        .cloned()
+}
+
+/// Next time the event happens after `now`.
+///
+/// Searches up to `horizon_days` ahead which is relevant when polar conditions skip events.
+#[must_use]
+pub fn next_solar_event<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+    horizon_days: u16,
+) -> Option<DateTime<Tz>> {
+    // The day of suncalc might start before now so also check yesterday
+    (-1..=i64::from(horizon_days)).find_map(|days| {
+        solar_event_on_day(now, days, event, latitude, longitude, height).filter(|time| time > now)
+    })
+}
+
+/// Last time the event happened before `now`.
+///
+/// Searches up to `horizon_days` back which is relevant when polar conditions skip events.
+#[must_use]
+pub fn previous_solar_event<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+    horizon_days: u16,
+) -> Option<DateTime<Tz>> {
+    (-i64::from(horizon_days)..=1).rev().find_map(|days| {
+        solar_event_on_day(now, days, event, latitude, longitude, height).filter(|time| time < now)
+    })
+}
+
+/// Time to sleep until the [`next_solar_event`]
+#[must_use]
+pub fn duration_until_solar_event<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+    horizon_days: u16,
+) -> Option<Duration> {
+    let next = next_solar_event(now, event, latitude, longitude, height, horizon_days)?;
+    (next - now.clone()).to_std().ok()
+}
+
+/// Time passed since the [`previous_solar_event`]
+#[must_use]
+pub fn duration_since_solar_event<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    event: SolarEvent,
+    latitude: f64,
+    longitude: f64,
+    height: Option<f64>,
+    horizon_days: u16,
+) -> Option<Duration> {
+    let previous = previous_solar_event(now, event, latitude, longitude, height, horizon_days)?;
+    (now.clone() - previous).to_std().ok()
+}
+
 #[test]
 fn sunrise_sunset() {
     use chrono::Timelike as _;
 
     let datetime = DateTime::parse_from_rfc3339("2021-01-01T02:00:00+01:00").unwrap();
-    let (sunrise, sunset) = get_sunrise_sunset(&datetime, 53.5, 10.0, Some(5.0)).unwrap();
+    let SunriseSunset::Normal { sunrise, sunset } =
+        get_sunrise_sunset(&datetime, 53.5, 10.0, Some(5.0))
+    else {
+        panic!("Hamburg should have a normal day");
+    };
 
     #[cfg(feature = "std")]
     dbg!(sunrise, sunset);
@@ -84,6 +515,189 @@ fn sunrise_sunset() {
     assert_eq!(sunset.minute(), 12);
 }
 
+#[test]
+fn tromso_polar_day() {
+    let datetime = DateTime::parse_from_rfc3339("2023-06-21T00:30:00+02:00").unwrap();
+    assert_eq!(
+        get_sunrise_sunset(&datetime, 69.65, 18.96, None),
+        SunriseSunset::PolarDay
+    );
+    let brightness = calc_relative_brightness_of_time(&datetime, 69.65, 18.96, None);
+    float_eq::assert_float_eq!(brightness, 1.0, abs <= 0.001);
+}
+
+#[test]
+fn tromso_polar_night() {
+    let datetime = DateTime::parse_from_rfc3339("2023-12-21T12:00:00+01:00").unwrap();
+    assert_eq!(
+        get_sunrise_sunset(&datetime, 69.65, 18.96, None),
+        SunriseSunset::PolarNight
+    );
+}
+
+#[test]
+fn trondheim_white_night_brightness() {
+    // Sun at about -3°, it sets but civil dusk does not happen
+    let night = DateTime::parse_from_rfc3339("2023-06-21T01:30:00+02:00").unwrap();
+    let brightness = calc_relative_brightness_of_time(&night, 63.43, 10.39, None);
+    float_eq::assert_float_eq!(brightness, 0.0, abs <= 0.05);
+    let noon = DateTime::parse_from_rfc3339("2023-06-21T13:20:00+02:00").unwrap();
+    let brightness = calc_relative_brightness_of_time(&noon, 63.43, 10.39, None);
+    float_eq::assert_float_eq!(brightness, 1.0, abs <= 0.05);
+    let evening = DateTime::parse_from_rfc3339("2023-06-21T21:00:00+02:00").unwrap();
+    let brightness = calc_relative_brightness_of_time(&evening, 63.43, 10.39, None);
+    assert!(0.1 < brightness && brightness < 0.9, "{brightness}");
+}
+
+#[test]
+fn svalbard_polar_night_brightness() {
+    let datetime = DateTime::parse_from_rfc3339("2023-12-21T12:00:00+01:00").unwrap();
+    let brightness = calc_relative_brightness_of_time(&datetime, 78.22, 15.65, None);
+    float_eq::assert_float_eq!(brightness, 0.0, abs <= 0.001);
+}
+
+#[test]
+fn solar_events_in_order() {
+    let datetime = DateTime::parse_from_rfc3339("2023-02-17T12:00:00+01:00").unwrap();
+    let events = get_solar_events(&datetime, 53.5, 10.0, Some(5.0));
+    let ordered = [
+        events.astronomical_dawn,
+        events.nautical_dawn,
+        events.civil_dawn,
+        events.morning_blue_hour_end,
+        events.sunrise_begin,
+        events.sunrise_end,
+        events.morning_golden_hour_end,
+        events.solar_noon,
+        events.evening_golden_hour_begin,
+        events.sunset_begin,
+        events.sunset_end,
+        events.evening_blue_hour_begin,
+        events.civil_dusk,
+        events.nautical_dusk,
+        events.astronomical_dusk,
+    ]
+    .map(Option::unwrap);
+    for pair in ordered.windows(2) {
+        assert!(pair[0] < pair[1], "{pair:?}");
+    }
+    assert!(events.nadir.is_some());
+
+    let SunriseSunset::Normal { sunrise, sunset } =
+        get_sunrise_sunset(&datetime, 53.5, 10.0, Some(5.0))
+    else {
+        panic!("Hamburg should have a normal day");
+    };
+    assert_eq!(events.sunrise_begin, Some(sunrise));
+    assert_eq!(events.sunset_end, Some(sunset));
+}
+
+#[test]
+fn solar_events_blue_hour_elevation() {
+    let datetime = DateTime::parse_from_rfc3339("2023-02-17T12:00:00+01:00").unwrap();
+    let events = get_solar_events(&datetime, 53.5, 10.0, None);
+    for event in [events.morning_blue_hour_end, events.evening_blue_hour_begin] {
+        let position = sun_position(&event.unwrap(), 53.5, 10.0);
+        float_eq::assert_float_eq!(position.elevation, -4.0, abs <= 0.01);
+    }
+}
+
+#[test]
+fn solar_events_summer_has_no_astronomical_night() {
+    let datetime = DateTime::parse_from_rfc3339("2024-06-20T12:00:00+02:00").unwrap();
+    let events = get_solar_events(&datetime, 53.5, 10.0, Some(5.0));
+    assert!(events.astronomical_dawn.is_none());
+    assert!(events.astronomical_dusk.is_none());
+    assert!(events.nautical_dawn.is_some());
+    assert!(events.civil_dusk.is_some());
+}
+
+#[test]
+fn next_sunset_later_today() {
+    let now = DateTime::parse_from_rfc3339("2023-02-17T10:00:00+01:00").unwrap();
+    let next = next_solar_event(&now, SolarEvent::SunsetEnd, 53.5, 10.0, Some(5.0), 2).unwrap();
+    assert_eq!(next.date_naive(), now.date_naive());
+    assert!(next > now);
+}
+
+#[test]
+fn next_sunset_tomorrow() {
+    let now = DateTime::parse_from_rfc3339("2023-02-17T22:00:00+01:00").unwrap();
+    let next = next_solar_event(&now, SolarEvent::SunsetEnd, 53.5, 10.0, Some(5.0), 2).unwrap();
+    assert_eq!(next.date_naive(), now.date_naive().succ_opt().unwrap());
+}
+
+#[test]
+fn next_sunrise_after_midnight() {
+    let now = DateTime::parse_from_rfc3339("2023-02-17T00:30:00+01:00").unwrap();
+    let next = next_solar_event(&now, SolarEvent::SunriseBegin, 53.5, 10.0, Some(5.0), 2).unwrap();
+    assert_eq!(next.date_naive(), now.date_naive());
+}
+
+#[test]
+fn previous_sunrise() {
+    let now = DateTime::parse_from_rfc3339("2023-02-17T05:00:00+01:00").unwrap();
+    let previous =
+        previous_solar_event(&now, SolarEvent::SunriseBegin, 53.5, 10.0, Some(5.0), 2).unwrap();
+    assert_eq!(previous.date_naive(), now.date_naive().pred_opt().unwrap());
+}
+
+#[test]
+fn duration_until_and_since_sunset() {
+    let now = DateTime::parse_from_rfc3339("2023-02-17T10:00:00+01:00").unwrap();
+    let until =
+        duration_until_solar_event(&now, SolarEvent::SunsetEnd, 53.5, 10.0, Some(5.0), 2).unwrap();
+    let sunset = next_solar_event(&now, SolarEvent::SunsetEnd, 53.5, 10.0, Some(5.0), 2).unwrap();
+    assert_eq!(now + chrono::Duration::from_std(until).unwrap(), sunset);
+
+    let later = sunset + chrono::Duration::try_minutes(5).unwrap();
+    let since = duration_since_solar_event(&later, SolarEvent::SunsetEnd, 53.5, 10.0, Some(5.0), 2)
+        .unwrap();
+    assert_eq!(since, Duration::from_secs(5 * 60));
+}
+
+#[test]
+fn next_sunset_skips_polar_day() {
+    let now = DateTime::parse_from_rfc3339("2023-06-01T12:00:00+02:00").unwrap();
+    assert!(next_solar_event(&now, SolarEvent::SunsetEnd, 69.65, 18.96, None, 10).is_none());
+    let next = next_solar_event(&now, SolarEvent::SunsetEnd, 69.65, 18.96, None, 90).unwrap();
+    assert_eq!(chrono::Datelike::month(&next), 7);
+}
+
+#[test]
+fn sun_position_winter_noon() {
+    let datetime = DateTime::parse_from_rfc3339("2022-12-21T12:19:00+01:00").unwrap();
+    let position = sun_position(&datetime, 53.5, 10.0);
+    float_eq::assert_float_eq!(position.azimuth, 180.0, abs <= 1.0);
+    float_eq::assert_float_eq!(position.elevation, 90.0 - 53.5 - 23.44, abs <= 0.3);
+}
+
+#[test]
+fn sun_position_summer_noon() {
+    let datetime = DateTime::parse_from_rfc3339("2024-06-20T13:22:00+02:00").unwrap();
+    let position = sun_position(&datetime, 53.5, 10.0);
+    float_eq::assert_float_eq!(position.azimuth, 180.0, abs <= 1.0);
+    float_eq::assert_float_eq!(position.elevation, 90.0 - 53.5 + 23.44, abs <= 0.3);
+}
+
+#[test]
+fn sun_position_morning_is_east() {
+    let datetime = DateTime::parse_from_rfc3339("2024-03-20T07:00:00+01:00").unwrap();
+    let position = sun_position(&datetime, 53.5, 10.0);
+    assert!((80.0..110.0).contains(&position.azimuth), "{position:?}");
+}
+
+#[test]
+fn sun_position_midnight() {
+    let datetime = DateTime::parse_from_rfc3339("2022-12-21T00:19:00+01:00").unwrap();
+    let position = sun_position(&datetime, 53.5, 10.0);
+    assert!(
+        position.azimuth < 5.0 || position.azimuth > 355.0,
+        "{position:?}"
+    );
+    assert!(position.elevation < -50.0, "{position:?}");
+}
+
 #[cfg(test)]
 fn test_relative_brightness(datetime: &str, expected: f32) {
     let datetime = DateTime::parse_from_rfc3339(datetime).unwrap();
@@ -93,6 +707,129 @@ fn test_relative_brightness(datetime: &str, expected: f32) {
     float_eq::assert_float_eq!(expected, relative_brightness, abs <= 0.05);
 }
 
+#[cfg(test)]
+fn brightness_with_curve(datetime: &str, curve: DaylightCurve, anchors: DaylightAnchors) -> f32 {
+    let datetime = DateTime::parse_from_rfc3339(datetime).unwrap();
+    calc_relative_brightness_with_curve(&datetime, 53.5, 10.0, Some(5.0), curve, anchors)
+}
+
+#[test]
+fn curve_default_is_cubic() {
+    for datetime in ["2023-02-17T07:33:00+01:00", "2023-02-17T15:00:00+01:00"] {
+        let default = calc_relative_brightness_of_time(
+            &DateTime::parse_from_rfc3339(datetime).unwrap(),
+            53.5,
+            10.0,
+            Some(5.0),
+        );
+        let cubic = brightness_with_curve(
+            datetime,
+            DaylightCurve::Exponent(3.0),
+            DaylightAnchors::DawnToDusk,
+        );
+        float_eq::assert_float_eq!(default, cubic, abs <= 0.000_1);
+    }
+}
+
+#[test]
+fn curve_lower_exponent_is_darker() {
+    let datetime = "2023-02-17T09:00:00+01:00";
+    let linear = brightness_with_curve(
+        datetime,
+        DaylightCurve::Exponent(1.0),
+        DaylightAnchors::DawnToDusk,
+    );
+    let cubic = brightness_with_curve(
+        datetime,
+        DaylightCurve::Exponent(3.0),
+        DaylightAnchors::DawnToDusk,
+    );
+    assert!(linear < cubic, "{linear} < {cubic}");
+}
+
+#[test]
+fn curve_sun_elevation_winter_noon() {
+    let brightness = brightness_with_curve(
+        "2022-12-21T12:19:00+01:00",
+        DaylightCurve::SunElevation,
+        DaylightAnchors::DawnToDusk,
+    );
+    float_eq::assert_float_eq!(brightness, 0.226, abs <= 0.005);
+}
+
+#[test]
+fn curve_plateau() {
+    let curve = DaylightCurve::Plateau;
+    let anchors = DaylightAnchors::DawnToDusk;
+    // Sunrise at 07:33 and sunset at 17:32
+    float_eq::assert_float_eq!(
+        brightness_with_curve("2023-02-17T07:40:00+01:00", curve, anchors),
+        1.0,
+        abs <= 0.001
+    );
+    float_eq::assert_float_eq!(
+        brightness_with_curve("2023-02-17T17:20:00+01:00", curve, anchors),
+        1.0,
+        abs <= 0.001
+    );
+    let ramp = brightness_with_curve("2023-02-17T07:15:00+01:00", curve, anchors);
+    assert!(0.2 < ramp && ramp < 0.8, "{ramp}");
+}
+
+#[test]
+fn curve_custom() {
+    let brightness = brightness_with_curve(
+        "2023-02-17T12:32:00+01:00",
+        DaylightCurve::Custom(|distance| 0.5 - distance),
+        DaylightAnchors::DawnToDusk,
+    );
+    float_eq::assert_float_eq!(brightness, 0.5, abs <= 0.02);
+    let clamped = brightness_with_curve(
+        "2023-02-17T12:32:00+01:00",
+        DaylightCurve::Custom(|_| 5.0),
+        DaylightAnchors::DawnToDusk,
+    );
+    float_eq::assert_float_eq!(clamped, 1.0, abs <= 0.001);
+}
+
+#[test]
+fn curve_exponent_not_positive() {
+    let anchors = DaylightAnchors::DawnToDusk;
+    for exponent in [-1.0, 0.0, f32::NAN] {
+        for datetime in ["2023-02-17T09:00:00+01:00", "2023-02-17T12:32:00+01:00"] {
+            let brightness =
+                brightness_with_curve(datetime, DaylightCurve::Exponent(exponent), anchors);
+            float_eq::assert_float_eq!(brightness, 0.0, abs <= 0.001);
+        }
+    }
+}
+
+#[test]
+fn anchors_sunrise_to_sunset() {
+    // Between civil dawn and sunrise
+    let datetime = "2023-02-17T07:15:00+01:00";
+    let curve = DaylightCurve::Exponent(3.0);
+    let dawn = brightness_with_curve(datetime, curve, DaylightAnchors::DawnToDusk);
+    let sunrise = brightness_with_curve(datetime, curve, DaylightAnchors::SunriseToSunset);
+    assert!(dawn > 0.0);
+    float_eq::assert_float_eq!(sunrise, 0.0, abs <= 0.001);
+}
+
+#[test]
+fn curve_sun_elevation_polar_day() {
+    let datetime = DateTime::parse_from_rfc3339("2023-06-21T13:00:00+02:00").unwrap();
+    let brightness = calc_relative_brightness_with_curve(
+        &datetime,
+        69.65,
+        18.96,
+        None,
+        DaylightCurve::SunElevation,
+        DaylightAnchors::SunriseToSunset,
+    );
+    // Sun at about 43.8°
+    float_eq::assert_float_eq!(brightness, 0.69, abs <= 0.02);
+}
+
 #[test]
 fn brightness_of_night() {
     test_relative_brightness("2021-01-01T02:00:00+01:00", 0.0);
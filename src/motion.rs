@@ -0,0 +1,554 @@
+use core::time::Duration;
+
+use crate::light::abs;
+
+/// Maximum velocity, acceleration and jerk of an actuator like a motorized cover or a slow dimmer.
+///
+/// All limits need to be positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionLimits {
+    /// Units per second
+    pub velocity: f32,
+    /// Units per second squared
+    pub acceleration: f32,
+    /// Units per second cubed.
+    /// `None` results in a trapezoidal profile, otherwise it is an S-curve.
+    pub jerk: Option<f32>,
+}
+
+/// Change from one velocity to another, or a constant velocity when both are the same
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Ramp {
+    start_position: f32,
+    start_velocity: f32,
+    end_velocity: f32,
+    /// Only used to release the acceleration of an interrupted S-curve, which then drops to zero
+    start_acceleration: f32,
+    seconds: f32,
+}
+
+/// Movement from a start position to a target within the [`MotionLimits`].
+///
+/// Instants are the time since any fixed point like the boot of the device or the UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionProfile {
+    limits: MotionLimits,
+    start_time: Duration,
+    target: f32,
+    /// Release the acceleration, then either stop, accelerate, cruise, decelerate or accelerate, cruise, decelerate, rest
+    ramps: [Ramp; 5],
+}
+
+impl MotionLimits {
+    /// Time to change the velocity by `change`
+    fn ramp_seconds(&self, change: f32) -> f32 {
+        let change = abs(change);
+        let acceleration = self.acceleration;
+        let Some(jerk) = self.jerk else {
+            return change / acceleration;
+        };
+        if change >= acceleration * acceleration / jerk {
+            change / acceleration + acceleration / jerk
+        } else {
+            2.0 * libm::sqrtf(change / jerk)
+        }
+    }
+
+    /// Both profiles are symmetric so the average velocity is in the middle
+    fn ramp_distance(&self, from: f32, to: f32) -> f32 {
+        f32::midpoint(from, to) * self.ramp_seconds(to - from)
+    }
+
+    /// Distance when accelerating to the peak and stopping right away
+    fn peak_distance(&self, velocity: f32, peak: f32) -> f32 {
+        self.ramp_distance(velocity, peak) + self.ramp_distance(peak, 0.0)
+    }
+}
+
+impl Ramp {
+    const fn rest(position: f32) -> Self {
+        Self {
+            start_position: position,
+            start_velocity: 0.0,
+            end_velocity: 0.0,
+            start_acceleration: 0.0,
+            seconds: 0.0,
+        }
+    }
+
+    fn new(limits: &MotionLimits, position: f32, velocity: f32, end_velocity: f32) -> Self {
+        Self {
+            start_position: position,
+            start_velocity: velocity,
+            end_velocity,
+            start_acceleration: 0.0,
+            seconds: limits.ramp_seconds(end_velocity - velocity),
+        }
+    }
+
+    /// Brings the acceleration down to zero with the jerk limit.
+    /// Without a jerk limit the acceleration can change instantly so this takes no time.
+    fn release(limits: &MotionLimits, position: f32, velocity: f32, acceleration: f32) -> Self {
+        let seconds = limits.jerk.map_or(0.0, |jerk| abs(acceleration) / jerk);
+        Self {
+            start_position: position,
+            start_velocity: velocity,
+            end_velocity: velocity + acceleration * seconds / 2.0,
+            start_acceleration: if seconds > 0.0 { acceleration } else { 0.0 },
+            seconds,
+        }
+    }
+
+    fn end_position(&self, limits: &MotionLimits) -> f32 {
+        self.state_at(limits, self.seconds).0
+    }
+
+    /// Position, velocity and acceleration at the time since the start of the ramp
+    fn state_at(&self, limits: &MotionLimits, time: f32) -> (f32, f32, f32) {
+        let time = time.clamp(0.0, self.seconds);
+        let (distance, velocity, acceleration) = match limits.jerk {
+            None => self.trapezoidal_at(time),
+            Some(jerk) if self.start_acceleration != 0.0 => self.release_at(jerk, time),
+            Some(jerk) => self.s_curve_at(limits.acceleration, jerk, time),
+        };
+        (self.start_position + distance, velocity, acceleration)
+    }
+
+    /// Distance, velocity and acceleration with constant acceleration
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    fn trapezoidal_at(&self, time: f32) -> (f32, f32, f32) {
+        let start = self.start_velocity;
+        let acceleration = if self.seconds > 0.0 {
+            (self.end_velocity - start) / self.seconds
+        } else {
+            0.0
+        };
+        (
+            start * time + acceleration * time * time / 2.0,
+            start + acceleration * time,
+            acceleration,
+        )
+    }
+
+    /// Distance, velocity and acceleration with the acceleration dropping from the start to zero
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    fn release_at(&self, jerk: f32, time: f32) -> (f32, f32, f32) {
+        let start = self.start_velocity;
+        let acceleration = self.start_acceleration;
+        let jerk = if acceleration < 0.0 { -jerk } else { jerk };
+        (
+            start * time + acceleration * time * time / 2.0 - jerk * time * time * time / 6.0,
+            start + acceleration * time - jerk * time * time / 2.0,
+            acceleration - jerk * time,
+        )
+    }
+
+    /// Distance, velocity and acceleration with the acceleration increasing, holding and decreasing again
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    fn s_curve_at(&self, acceleration: f32, jerk: f32, time: f32) -> (f32, f32, f32) {
+        let start = self.start_velocity;
+        let change = self.end_velocity - start;
+        let sign = if change < 0.0 { -1.0 } else { 1.0 };
+        let jerk_time = if abs(change) >= acceleration * acceleration / jerk {
+            acceleration / jerk
+        } else {
+            libm::sqrtf(abs(change) / jerk)
+        };
+        let peak = sign * jerk * jerk_time;
+        let jerk = sign * jerk;
+        let constant_time = (self.seconds - 2.0 * jerk_time).max(0.0);
+
+        if time < jerk_time {
+            return (
+                start * time + jerk * time * time * time / 6.0,
+                start + jerk * time * time / 2.0,
+                jerk * time,
+            );
+        }
+        let first_velocity = start + jerk * jerk_time * jerk_time / 2.0;
+        let first_distance = start * jerk_time + jerk * jerk_time * jerk_time * jerk_time / 6.0;
+        let time = time - jerk_time;
+        if time < constant_time {
+            return (
+                first_distance + first_velocity * time + peak * time * time / 2.0,
+                first_velocity + peak * time,
+                peak,
+            );
+        }
+        let second_velocity = first_velocity + peak * constant_time;
+        let second_distance = first_distance
+            + first_velocity * constant_time
+            + peak * constant_time * constant_time / 2.0;
+        let time = time - constant_time;
+        (
+            second_distance + second_velocity * time + peak * time * time / 2.0
+                - jerk * time * time * time / 6.0,
+            second_velocity + peak * time - jerk * time * time / 2.0,
+            peak - jerk * time,
+        )
+    }
+}
+
+/// Accelerate to the peak velocity, cruise and decelerate to stop at the target.
+///
+/// Needs to be able to stop before passing the target.
+fn approach(limits: &MotionLimits, position: f32, velocity: f32, target: f32) -> [Ramp; 3] {
+    let direction = if target < position { -1.0 } else { 1.0 };
+    let distance = (target - position) * direction;
+    let towards = velocity * direction;
+    let max = limits.velocity;
+
+    let peak = if distance >= limits.peak_distance(towards, max) {
+        max
+    } else {
+        let mut lower = towards.clamp(0.0, max);
+        let mut upper = max;
+        for _ in 0..32 {
+            let middle = f32::midpoint(lower, upper);
+            if limits.peak_distance(towards, middle) < distance {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        f32::midpoint(lower, upper)
+    };
+    let cruise_seconds = if peak > 0.0 {
+        ((distance - limits.peak_distance(towards, peak)) / peak).max(0.0)
+    } else {
+        0.0
+    };
+
+    let accelerate = Ramp::new(limits, position, velocity, peak * direction);
+    let cruise = Ramp {
+        start_position: accelerate.end_position(limits),
+        start_velocity: peak * direction,
+        end_velocity: peak * direction,
+        start_acceleration: 0.0,
+        seconds: cruise_seconds,
+    };
+    let decelerate = Ramp::new(limits, cruise.end_position(limits), peak * direction, 0.0);
+    [accelerate, cruise, decelerate]
+}
+
+impl MotionProfile {
+    /// Plans the fastest movement to the target within the limits.
+    ///
+    /// When the target can not be reached without passing it at the `start_velocity` the movement stops first and comes back.
+    /// The acceleration at the start is assumed to be zero.
+    #[must_use]
+    pub fn plan(
+        start_time: Duration,
+        start: f32,
+        start_velocity: f32,
+        target: f32,
+        limits: MotionLimits,
+    ) -> Self {
+        let release = Ramp::release(&limits, start, start_velocity, 0.0);
+        Self::plan_after(start_time, release, target, limits)
+    }
+
+    /// Plans the movement which follows releasing the acceleration
+    fn plan_after(start_time: Duration, release: Ramp, target: f32, limits: MotionLimits) -> Self {
+        let start = release.end_position(&limits);
+        let start_velocity = release.end_velocity;
+        let direction = if target < start { -1.0 } else { 1.0 };
+        let distance = (target - start) * direction;
+        let towards = start_velocity * direction;
+        let slowest_peak = towards.clamp(0.0, limits.velocity);
+
+        let ramps = if towards > 0.0 && distance < limits.peak_distance(towards, slowest_peak) {
+            let stop = Ramp::new(&limits, start, start_velocity, 0.0);
+            let [accelerate, cruise, decelerate] =
+                approach(&limits, stop.end_position(&limits), 0.0, target);
+            [release, stop, accelerate, cruise, decelerate]
+        } else {
+            let [accelerate, cruise, decelerate] = approach(&limits, start, start_velocity, target);
+            [release, accelerate, cruise, decelerate, Ramp::rest(target)]
+        };
+        Self {
+            limits,
+            start_time,
+            target,
+            ramps,
+        }
+    }
+
+    #[must_use]
+    pub const fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Total time of the movement
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        let seconds = self.ramps.iter().map(|ramp| ramp.seconds).sum();
+        Duration::try_from_secs_f32(seconds).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn position_at(&self, now: Duration) -> f32 {
+        self.state_at(now).0
+    }
+
+    /// Units per second at the given instant
+    #[must_use]
+    pub fn velocity_at(&self, now: Duration) -> f32 {
+        self.state_at(now).1
+    }
+
+    /// Units per second squared at the given instant
+    #[must_use]
+    pub fn acceleration_at(&self, now: Duration) -> f32 {
+        self.state_at(now).2
+    }
+
+    #[must_use]
+    pub fn is_finished(&self, now: Duration) -> bool {
+        now.saturating_sub(self.start_time) >= self.duration()
+    }
+
+    /// Continues from the current position, velocity and acceleration towards a new target.
+    ///
+    /// The same target keeps the movement as it is.
+    /// With a jerk limit the current acceleration is brought down to zero before heading to the new target.
+    pub fn replan(&mut self, target: f32, now: Duration) {
+        #[expect(
+            clippy::float_cmp,
+            reason = "only the exact same target keeps the plan"
+        )]
+        if target == self.target {
+            return;
+        }
+        let (position, velocity, acceleration) = self.state_at(now);
+        let release = Ramp::release(&self.limits, position, velocity, acceleration);
+        *self = Self::plan_after(now, release, target, self.limits);
+    }
+
+    fn state_at(&self, now: Duration) -> (f32, f32, f32) {
+        let Some(elapsed) = now.checked_sub(self.start_time) else {
+            let first = &self.ramps[0];
+            return (first.start_position, first.start_velocity, 0.0);
+        };
+        let mut time = elapsed.as_secs_f32();
+        for ramp in &self.ramps {
+            if time < ramp.seconds {
+                return ramp.state_at(&self.limits, time);
+            }
+            time -= ramp.seconds;
+        }
+        (self.target, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRAPEZOIDAL: MotionLimits = MotionLimits {
+        velocity: 10.0,
+        acceleration: 5.0,
+        jerk: None,
+    };
+
+    const S_CURVE: MotionLimits = MotionLimits {
+        velocity: 10.0,
+        acceleration: 5.0,
+        jerk: Some(5.0),
+    };
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    /// Checks continuity and the limits over the whole profile
+    fn assert_within_limits(profile: &MotionProfile, limits: &MotionLimits) {
+        assert_within_limits_from(profile, limits, Duration::ZERO);
+    }
+
+    /// Checks continuity and the limits from the given instant on
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    fn assert_within_limits_from(profile: &MotionProfile, limits: &MotionLimits, from: Duration) {
+        let step = 0.001;
+        let mut last_position = profile.position_at(from);
+        let mut last_velocity = profile.velocity_at(from);
+        let mut last_acceleration = profile.acceleration_at(from);
+        let end = from + profile.duration() + Duration::from_secs(1);
+        for millis in from.as_millis() + 1..=end.as_millis() {
+            let now = Duration::from_millis(u64::try_from(millis).unwrap());
+            let position = profile.position_at(now);
+            let velocity = profile.velocity_at(now);
+            let acceleration = profile.acceleration_at(now);
+            let time = now.as_secs_f32();
+            assert!(
+                abs(velocity) <= limits.velocity + 0.01,
+                "{velocity} too fast at {time}"
+            );
+            assert!(
+                abs(position - last_position) <= limits.velocity * step + 0.01,
+                "position jumps at {time}"
+            );
+            assert!(
+                abs(velocity - last_velocity) <= limits.acceleration * step + 0.01,
+                "velocity jumps at {time}"
+            );
+            if let Some(jerk) = limits.jerk {
+                assert!(
+                    abs(acceleration) <= limits.acceleration + 0.01,
+                    "{acceleration} accelerates too fast at {time}"
+                );
+                assert!(
+                    abs(acceleration - last_acceleration) <= jerk * step + 0.01,
+                    "acceleration jumps at {time}"
+                );
+            }
+            last_position = position;
+            last_velocity = velocity;
+            last_acceleration = acceleration;
+        }
+    }
+
+    #[test]
+    fn trapezoidal_with_cruise() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, TRAPEZOIDAL);
+        float_eq::assert_float_eq!(profile.duration().as_secs_f32(), 12.0, abs <= 0.001);
+        float_eq::assert_float_eq!(profile.position_at(secs(1.0)), 2.5, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(secs(6.0)), 50.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.velocity_at(secs(6.0)), 10.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(secs(12.0)), 100.0, abs <= 0.01);
+        assert!(profile.is_finished(secs(12.0)));
+        assert!(!profile.is_finished(secs(11.0)));
+        assert_within_limits(&profile, &TRAPEZOIDAL);
+    }
+
+    #[test]
+    fn trapezoidal_short_is_triangular() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 5.0, TRAPEZOIDAL);
+        let peak_time = libm::sqrtf(5.0 / 5.0);
+        float_eq::assert_float_eq!(
+            profile.duration().as_secs_f32(),
+            2.0 * peak_time,
+            abs <= 0.001
+        );
+        float_eq::assert_float_eq!(profile.position_at(secs(peak_time)), 2.5, abs <= 0.01);
+        assert_within_limits(&profile, &TRAPEZOIDAL);
+    }
+
+    #[test]
+    fn s_curve_with_cruise() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, S_CURVE);
+        // 3 seconds and 15 units for each ramp
+        float_eq::assert_float_eq!(profile.duration().as_secs_f32(), 13.0, abs <= 0.001);
+        float_eq::assert_float_eq!(profile.position_at(secs(6.5)), 50.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(secs(3.0)), 15.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(secs(13.0)), 100.0, abs <= 0.01);
+        assert_within_limits(&profile, &S_CURVE);
+    }
+
+    #[test]
+    fn s_curve_starts_smooth() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, S_CURVE);
+        // Jerk limited so the acceleration builds up slowly
+        float_eq::assert_float_eq!(profile.velocity_at(secs(0.1)), 0.025, abs <= 0.001);
+    }
+
+    #[test]
+    fn s_curve_short() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 3.0, S_CURVE);
+        float_eq::assert_float_eq!(
+            profile.position_at(profile.duration() / 2),
+            1.5,
+            abs <= 0.01
+        );
+        float_eq::assert_float_eq!(profile.position_at(profile.duration()), 3.0, abs <= 0.01);
+        assert_within_limits(&profile, &S_CURVE);
+    }
+
+    #[test]
+    fn negative_direction() {
+        let profile = MotionProfile::plan(Duration::ZERO, 100.0, 0.0, 0.0, TRAPEZOIDAL);
+        float_eq::assert_float_eq!(profile.duration().as_secs_f32(), 12.0, abs <= 0.001);
+        float_eq::assert_float_eq!(profile.position_at(secs(1.0)), 97.5, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.velocity_at(secs(6.0)), -10.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn before_start() {
+        let profile = MotionProfile::plan(secs(10.0), 20.0, 0.0, 100.0, TRAPEZOIDAL);
+        float_eq::assert_float_eq!(profile.position_at(secs(5.0)), 20.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(secs(11.0)), 22.5, abs <= 0.01);
+    }
+
+    #[test]
+    fn already_there() {
+        let profile = MotionProfile::plan(Duration::ZERO, 42.0, 0.0, 42.0, S_CURVE);
+        assert!(profile.duration() < Duration::from_millis(1));
+        float_eq::assert_float_eq!(profile.position_at(secs(1.0)), 42.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn too_fast_passes_and_returns() {
+        let profile = MotionProfile::plan(Duration::ZERO, 0.0, 10.0, 1.0, TRAPEZOIDAL);
+        // Stopping from 10 takes 2 seconds and 10 units
+        float_eq::assert_float_eq!(profile.position_at(secs(2.0)), 10.0, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.position_at(profile.duration()), 1.0, abs <= 0.01);
+        assert_within_limits(&profile, &TRAPEZOIDAL);
+    }
+
+    #[test]
+    fn moving_away_turns_around() {
+        let profile = MotionProfile::plan(Duration::ZERO, 50.0, -10.0, 100.0, S_CURVE);
+        assert!(profile.position_at(secs(1.0)) < 50.0);
+        float_eq::assert_float_eq!(profile.position_at(profile.duration()), 100.0, abs <= 0.01);
+        assert_within_limits(&profile, &S_CURVE);
+    }
+
+    #[test]
+    fn replan_is_continuous() {
+        for limits in [TRAPEZOIDAL, S_CURVE] {
+            let mut profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, limits);
+            let now = secs(4.0);
+            let position = profile.position_at(now);
+            let velocity = profile.velocity_at(now);
+            profile.replan(30.0, now);
+            float_eq::assert_float_eq!(profile.position_at(now), position, abs <= 0.01);
+            float_eq::assert_float_eq!(profile.velocity_at(now), velocity, abs <= 0.01);
+            float_eq::assert_float_eq!(profile.target(), 30.0, abs <= 0.01);
+            float_eq::assert_float_eq!(
+                profile.position_at(now + profile.duration()),
+                30.0,
+                abs <= 0.01
+            );
+        }
+    }
+
+    #[test]
+    fn replan_same_target_keeps_movement() {
+        for limits in [TRAPEZOIDAL, S_CURVE] {
+            let planned = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, limits);
+            let mut profile = planned;
+            profile.replan(100.0, secs(1.5));
+            assert_eq!(profile, planned);
+        }
+    }
+
+    #[test]
+    fn replan_while_accelerating_keeps_acceleration() {
+        let mut profile = MotionProfile::plan(Duration::ZERO, 0.0, 0.0, 100.0, S_CURVE);
+        let now = secs(1.5);
+        let position = profile.position_at(now);
+        let velocity = profile.velocity_at(now);
+        let acceleration = profile.acceleration_at(now);
+        float_eq::assert_float_eq!(acceleration, 5.0, abs <= 0.01);
+        profile.replan(30.0, now);
+        float_eq::assert_float_eq!(profile.position_at(now), position, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.velocity_at(now), velocity, abs <= 0.01);
+        float_eq::assert_float_eq!(profile.acceleration_at(now), acceleration, abs <= 0.01);
+        float_eq::assert_float_eq!(
+            profile.position_at(now + profile.duration()),
+            30.0,
+            abs <= 0.01
+        );
+        assert_within_limits_from(&profile, &S_CURVE, now);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::light::normalize_hue;
+use crate::rgb::{linear_to_srgb, srgb_to_linear};
+use crate::{Hsv, Rgb, angle_distance, interpolate};
+
+/// Perceptual color space by Björn Ottosson
+#[derive(Clone, Copy)]
+pub struct Oklab {
+    /// Perceived lightness from 0.0 to 1.0
+    pub lightness: f32,
+    /// Green (negative) to red (positive), also known as `a`
+    pub green_red: f32,
+    /// Blue (negative) to yellow (positive), also known as `b`
+    pub blue_yellow: f32,
+}
+
+/// Cylindrical form of [`Oklab`]
+#[derive(Clone, Copy)]
+pub struct Oklch {
+    /// Perceived lightness from 0.0 to 1.0
+    pub lightness: f32,
+    /// Colorfulness from 0.0 to around 0.4
+    pub chroma: f32,
+    /// Hue from 0.0 to 360.0
+    pub hue: f32,
+}
+
+impl Oklab {
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn from_rgb(rgb: &Rgb) -> Self {
+        let red = srgb_to_linear(rgb.red / 100.0);
+        let green = srgb_to_linear(rgb.green / 100.0);
+        let blue = srgb_to_linear(rgb.blue / 100.0);
+
+        let long = libm::cbrtf(0.412_221_46 * red + 0.536_332_55 * green + 0.051_445_995 * blue);
+        let medium = libm::cbrtf(0.211_903_5 * red + 0.680_699_5 * green + 0.107_396_96 * blue);
+        let short = libm::cbrtf(0.088_302_46 * red + 0.281_718_85 * green + 0.629_978_7 * blue);
+
+        Self {
+            lightness: 0.210_454_26 * long + 0.793_617_8 * medium - 0.004_072_047 * short,
+            green_red: 1.977_998_5 * long - 2.428_592_2 * medium + 0.450_593_7 * short,
+            blue_yellow: 0.025_904_037 * long + 0.782_771_77 * medium - 0.808_675_77 * short,
+        }
+    }
+
+    /// Colors outside of sRGB are clamped per channel
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn to_rgb(self) -> Rgb {
+        let long = self.lightness + 0.396_337_78 * self.green_red + 0.215_803_76 * self.blue_yellow;
+        let medium =
+            self.lightness - 0.105_561_346 * self.green_red - 0.063_854_17 * self.blue_yellow;
+        let short = self.lightness - 0.089_484_18 * self.green_red - 1.291_485_5 * self.blue_yellow;
+
+        let long = long * long * long;
+        let medium = medium * medium * medium;
+        let short = short * short * short;
+
+        let red = 4.076_741_7 * long - 3.307_711_6 * medium + 0.230_969_94 * short;
+        let green = -1.268_438 * long + 2.609_757_4 * medium - 0.341_319_38 * short;
+        let blue = -0.004_196_086_3 * long - 0.703_418_6 * medium + 1.707_614_7 * short;
+
+        let channel = |linear: f32| linear_to_srgb(linear.clamp(0.0, 1.0)) * 100.0;
+        Rgb {
+            red: channel(red),
+            green: channel(green),
+            blue: channel(blue),
+        }
+    }
+
+    /// Interpolates linear which is perceived as an even fade
+    #[must_use]
+    pub fn calculate_interpolated(start: &Self, end: &Self, position: f32) -> Self {
+        if position <= 0.0 {
+            *start
+        } else if position >= 1.0 {
+            *end
+        } else {
+            Self {
+                lightness: interpolate::f32(start.lightness, end.lightness, position),
+                green_red: interpolate::f32(start.green_red, end.green_red, position),
+                blue_yellow: interpolate::f32(start.blue_yellow, end.blue_yellow, position),
+            }
+        }
+    }
+}
+
+impl Oklch {
+    /// Interpolates with the shortest way around the hue circle.
+    ///
+    /// When one side has no chroma its hue is meaningless so the hue of the other side is used.
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn calculate_interpolated(start: &Self, end: &Self, position: f32) -> Self {
+        /// Below this chroma the hue is not visible
+        const ACHROMATIC: f32 = 0.000_1;
+
+        if position <= 0.0 {
+            return *start;
+        } else if position >= 1.0 {
+            return *end;
+        }
+
+        let start_hue = if start.chroma < ACHROMATIC {
+            end.hue
+        } else {
+            start.hue
+        };
+        let end_hue = if end.chroma < ACHROMATIC {
+            start_hue
+        } else {
+            end.hue
+        };
+        Self {
+            lightness: interpolate::f32(start.lightness, end.lightness, position),
+            chroma: interpolate::f32(start.chroma, end.chroma, position),
+            hue: normalize_hue(angle_distance(start_hue, end_hue) * position + start_hue),
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let hue = libm::atan2f(lab.blue_yellow, lab.green_red).to_degrees();
+        Self {
+            lightness: lab.lightness,
+            chroma: libm::hypotf(lab.green_red, lab.blue_yellow),
+            hue: normalize_hue(hue),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let hue = lch.hue.to_radians();
+        Self {
+            lightness: lch.lightness,
+            green_red: lch.chroma * libm::cosf(hue),
+            blue_yellow: lch.chroma * libm::sinf(hue),
+        }
+    }
+}
+
+impl From<Hsv> for Oklab {
+    fn from(hsv: Hsv) -> Self {
+        Self::from_rgb(&hsv.into())
+    }
+}
+
+impl From<Oklab> for Hsv {
+    fn from(lab: Oklab) -> Self {
+        lab.to_rgb().into()
+    }
+}
+
+impl From<Hsv> for Oklch {
+    fn from(hsv: Hsv) -> Self {
+        Oklab::from(hsv).into()
+    }
+}
+
+impl From<Oklch> for Hsv {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+#[test]
+fn white() {
+    let result = Oklab::from(Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    });
+    float_eq::assert_float_eq!(result.lightness, 1.0, abs <= 0.001);
+    float_eq::assert_float_eq!(result.green_red, 0.0, abs <= 0.001);
+    float_eq::assert_float_eq!(result.blue_yellow, 0.0, abs <= 0.001);
+}
+
+#[test]
+fn red() {
+    let result = Oklch::from(Hsv::from_hue(0.0));
+    float_eq::assert_float_eq!(result.lightness, 0.628, abs <= 0.001);
+    float_eq::assert_float_eq!(result.chroma, 0.258, abs <= 0.001);
+    float_eq::assert_float_eq!(result.hue, 29.23, abs <= 0.1);
+}
+
+#[test]
+fn hsv_roundtrip() {
+    for hue in [0.0, 60.0, 120.0, 200.0, 300.0] {
+        let hsv = Hsv {
+            hue,
+            saturation: 70.0,
+            brightness: 40.0,
+        };
+        let result = Hsv::from(Oklch::from(hsv));
+        float_eq::assert_float_eq!(angle_distance(result.hue, hue), 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.saturation, 70.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 40.0, abs <= 0.1);
+    }
+}
+
+#[cfg(test)]
+mod interpolate_tests {
+    use super::*;
+
+    #[test]
+    fn lab_half_between_black_and_white() {
+        let black = Oklab::from(Hsv {
+            hue: 0.0,
+            saturation: 0.0,
+            brightness: 0.0,
+        });
+        let white = Oklab::from(Hsv {
+            hue: 0.0,
+            saturation: 0.0,
+            brightness: 100.0,
+        });
+        let result = Oklab::calculate_interpolated(&black, &white, 0.5);
+        float_eq::assert_float_eq!(result.lightness, 0.5, abs <= 0.001);
+    }
+
+    #[test]
+    fn lch_takes_shortest_hue() {
+        let start = Oklch {
+            lightness: 0.5,
+            chroma: 0.1,
+            hue: 350.0,
+        };
+        let end = Oklch {
+            lightness: 0.7,
+            chroma: 0.2,
+            hue: 30.0,
+        };
+        let result = Oklch::calculate_interpolated(&start, &end, 0.25);
+        float_eq::assert_float_eq!(result.lightness, 0.55, abs <= 0.001);
+        float_eq::assert_float_eq!(result.chroma, 0.125, abs <= 0.001);
+        float_eq::assert_float_eq!(result.hue, 0.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn lch_from_gray_keeps_hue() {
+        let start = Oklch {
+            lightness: 0.5,
+            chroma: 0.0,
+            hue: 0.0,
+        };
+        let end = Oklch {
+            lightness: 0.5,
+            chroma: 0.2,
+            hue: 250.0,
+        };
+        let result = Oklch::calculate_interpolated(&start, &end, 0.5);
+        float_eq::assert_float_eq!(result.hue, 250.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn lch_complementary_keeps_lightness_even() {
+        let start = Oklch::from(Hsv::from_hue(60.0));
+        let end = Oklch::from(Hsv::from_hue(240.0));
+        let result = Oklch::calculate_interpolated(&start, &end, 0.5);
+        float_eq::assert_float_eq!(
+            result.lightness,
+            f32::midpoint(start.lightness, end.lightness),
+            abs <= 0.001
+        );
+    }
+
+    #[test]
+    fn before_zero_and_after_one() {
+        let start = Oklch::from(Hsv::from_hue(60.0));
+        let end = Oklch::from(Hsv::from_hue(240.0));
+        let result = Oklch::calculate_interpolated(&start, &end, -1.0);
+        float_eq::assert_float_eq!(result.hue, start.hue, abs <= 0.001);
+        let result = Oklch::calculate_interpolated(&start, &end, 2.0);
+        float_eq::assert_float_eq!(result.hue, end.hue, abs <= 0.001);
+    }
+}
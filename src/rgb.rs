@@ -0,0 +1,233 @@
+use crate::light::approach_linear;
+use crate::{BrightnessCurve, Hsv, PwmResolution, interpolate};
+
+#[derive(Clone, Copy)]
+pub struct Rgb {
+    /// Red from 0.0 to 100.0
+    pub red: f32,
+    /// Green from 0.0 to 100.0
+    pub green: f32,
+    /// Blue from 0.0 to 100.0
+    pub blue: f32,
+}
+
+impl Rgb {
+    /// Converts from `u8` RGB values
+    #[must_use]
+    pub fn from_u8(red: u8, green: u8, blue: u8) -> Self {
+        const FACTOR: f32 = 100.0 / u8::MAX as f32;
+        Self {
+            red: f32::from(red) * FACTOR,
+            green: f32::from(green) * FACTOR,
+            blue: f32::from(blue) * FACTOR,
+        }
+    }
+
+    /// Converts to `u8` RGB values rounded to the nearest value so that [`Rgb::from_u8`] round trips.
+    ///
+    /// Unlike [`Hsv::to_rgb_u8`] which truncates. 50% gray is `(128, 128, 128)` here and `(127, 127, 127)` there.
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn to_u8(self) -> (u8, u8, u8) {
+        const FACTOR: f32 = u8::MAX as f32 / 100.0;
+        (
+            (self.red * FACTOR + 0.5) as u8,
+            (self.green * FACTOR + 0.5) as u8,
+            (self.blue * FACTOR + 0.5) as u8,
+        )
+    }
+
+    /// Converts to PWM duty cycles with the curve applied to each channel
+    #[must_use]
+    pub fn to_duty(self, curve: &BrightnessCurve, resolution: PwmResolution) -> (u16, u16, u16) {
+        (
+            curve.duty(self.red, resolution),
+            curve.duty(self.green, resolution),
+            curve.duty(self.blue, resolution),
+        )
+    }
+
+    #[must_use]
+    pub fn calculate_interpolated(start: &Self, end: &Self, position: f32) -> Self {
+        if position <= 0.0 {
+            *start
+        } else if position >= 1.0 {
+            *end
+        } else {
+            Self {
+                red: interpolate::f32(start.red, end.red, position),
+                green: interpolate::f32(start.green, end.green, position),
+                blue: interpolate::f32(start.blue, end.blue, position),
+            }
+        }
+    }
+
+    /// Approach the target by `step_size` on each channel independently
+    #[must_use]
+    pub fn approach(&self, target: &Self, step_size: f32) -> Self {
+        Self {
+            red: approach_linear(self.red, target.red, step_size),
+            green: approach_linear(self.green, target.green, step_size),
+            blue: approach_linear(self.blue, target.blue, step_size),
+        }
+    }
+}
+
+/// Removes the sRGB gamma of a channel from 0.0 to 1.0
+pub fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.040_45 {
+        channel / 12.92
+    } else {
+        libm::powf((channel + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Applies the sRGB gamma to a linear channel from 0.0 to 1.0
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+pub fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * libm::powf(channel, 1.0 / 2.4) - 0.055
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Self {
+        let (red, green, blue) = hsv.to_rgb_f32();
+        Self {
+            red: red * 100.0,
+            green: green * 100.0,
+            blue: blue * 100.0,
+        }
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Self {
+        Self::from_rgb_f32(rgb.red / 100.0, rgb.green / 100.0, rgb.blue / 100.0)
+    }
+}
+
+#[test]
+fn from_hsv() {
+    let result = Rgb::from(Hsv::from_hue(60.0));
+    float_eq::assert_float_eq!(result.red, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.green, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.blue, 0.0, abs <= 0.1);
+}
+
+#[test]
+fn into_hsv() {
+    let result = Hsv::from(Rgb {
+        red: 0.0,
+        green: 50.0,
+        blue: 50.0,
+    });
+    float_eq::assert_float_eq!(result.hue, 180.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.saturation, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.1);
+}
+
+#[test]
+fn srgb_gamma_roundtrip() {
+    for channel in [0.0, 0.02, 0.2, 0.5, 0.8, 1.0] {
+        float_eq::assert_float_eq!(
+            linear_to_srgb(srgb_to_linear(channel)),
+            channel,
+            abs <= 0.0001
+        );
+    }
+    float_eq::assert_float_eq!(srgb_to_linear(0.5), 0.214, abs <= 0.001);
+}
+
+#[test]
+fn u8_roundtrip() {
+    let (red, green, blue) = Rgb::from_u8(255, 127, 0).to_u8();
+    assert_eq!(red, 255);
+    assert_eq!(green, 127);
+    assert_eq!(blue, 0);
+}
+
+#[test]
+fn to_u8_rounds_unlike_hsv() {
+    let gray = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 50.0,
+    };
+    assert_eq!(Rgb::from(gray).to_u8(), (128, 128, 128));
+    assert_eq!(gray.to_rgb_u8(), (127, 127, 127));
+}
+
+#[test]
+fn to_duty_applies_curve_per_channel() {
+    let rgb = Rgb {
+        red: 100.0,
+        green: 50.0,
+        blue: 0.0,
+    };
+    let (red, green, blue) = rgb.to_duty(&BrightnessCurve::Gamma(2.0), PwmResolution::Bits10);
+    assert_eq!(red, 1023);
+    assert_eq!(green, 256);
+    assert_eq!(blue, 0);
+}
+
+#[test]
+fn approach_each_channel() {
+    let current = Rgb {
+        red: 50.0,
+        green: 50.0,
+        blue: 50.0,
+    };
+    let target = Rgb {
+        red: 100.0,
+        green: 0.0,
+        blue: 50.5,
+    };
+    let result = current.approach(&target, 1.0);
+    float_eq::assert_float_eq!(result.red, 51.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.green, 49.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.blue, 50.5, abs <= 0.01);
+}
+
+#[cfg(test)]
+mod interpolate_tests {
+    use super::Rgb;
+
+    const START: Rgb = Rgb {
+        red: 0.0,
+        green: 100.0,
+        blue: 50.0,
+    };
+    const END: Rgb = Rgb {
+        red: 100.0,
+        green: 0.0,
+        blue: 50.0,
+    };
+
+    #[test]
+    fn before_zero() {
+        let result = Rgb::calculate_interpolated(&START, &END, -1.0);
+        float_eq::assert_float_eq!(result.red, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 100.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 50.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn quarter() {
+        let result = Rgb::calculate_interpolated(&START, &END, 0.25);
+        float_eq::assert_float_eq!(result.red, 25.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 75.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 50.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn after_one() {
+        let result = Rgb::calculate_interpolated(&START, &END, 2.0);
+        float_eq::assert_float_eq!(result.red, 100.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 50.0, abs <= 0.1);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{ColorTemperature, Hsv, Rgb, interpolate};
+
+/// Color temperature in Kelvin which is perceived as neutral white
+const NEUTRAL_KELVIN: f32 = 6600.0;
+
+/// RGB with an additional white channel like SK6812 RGBW LEDs
+#[derive(Clone, Copy)]
+pub struct Rgbw {
+    /// Red from 0.0 to 100.0
+    pub red: f32,
+    /// Green from 0.0 to 100.0
+    pub green: f32,
+    /// Blue from 0.0 to 100.0
+    pub blue: f32,
+    /// White from 0.0 to 100.0
+    pub white: f32,
+}
+
+/// RGB with additional cold and warm white channels like RGB+CCT controllers
+#[derive(Clone, Copy)]
+pub struct Rgbww {
+    /// Red from 0.0 to 100.0
+    pub red: f32,
+    /// Green from 0.0 to 100.0
+    pub green: f32,
+    /// Blue from 0.0 to 100.0
+    pub blue: f32,
+    /// Cold white from 0.0 to 100.0
+    pub cold_white: f32,
+    /// Warm white from 0.0 to 100.0
+    pub warm_white: f32,
+}
+
+impl Rgbw {
+    /// Moves the part of the color the white LED can produce into the white channel.
+    ///
+    /// `white_kelvin` is the color temperature of the white LED.
+    /// The emitted color and brightness stay the same assuming the white LED is as bright as all RGB LEDs combined.
+    #[must_use]
+    pub fn from_rgb(rgb: &Rgb, white_kelvin: f32) -> Self {
+        let (rgb, white) = extract_white(rgb, &white_led_color(white_kelvin));
+        Self {
+            red: rgb.red,
+            green: rgb.green,
+            blue: rgb.blue,
+            white,
+        }
+    }
+
+    /// See [`Rgbw::from_rgb`]
+    #[must_use]
+    pub fn from_hsv(hsv: &Hsv, white_kelvin: f32) -> Self {
+        Self::from_rgb(&Rgb::from(*hsv), white_kelvin)
+    }
+
+    /// Combines the white channel back into RGB, for example to map the state reported by a device.
+    ///
+    /// Colors brighter than representable are scaled down keeping their hue.
+    #[must_use]
+    pub fn to_rgb(self, white_kelvin: f32) -> Rgb {
+        let rgb = Rgb {
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+        };
+        add_white(&rgb, &white_led_color(white_kelvin), self.white)
+    }
+
+    /// See [`Rgbw::to_rgb`]
+    #[must_use]
+    pub fn to_hsv(self, white_kelvin: f32) -> Hsv {
+        self.to_rgb(white_kelvin).into()
+    }
+}
+
+impl Rgbww {
+    /// Moves the part of the color the white LEDs can produce into the white channels.
+    ///
+    /// Cold and warm white are mixed to get as close to neutral white as possible.
+    /// The emitted color and brightness stay the same assuming the white LEDs are as bright as all RGB LEDs combined.
+    #[must_use]
+    pub fn from_rgb(rgb: &Rgb, cold_kelvin: f32, warm_kelvin: f32) -> Self {
+        let warm_share = warm_share(NEUTRAL_KELVIN, cold_kelvin, warm_kelvin);
+        let led = mix_white_leds(cold_kelvin, warm_kelvin, warm_share);
+        let (rgb, white) = extract_white(rgb, &led);
+        Self {
+            red: rgb.red,
+            green: rgb.green,
+            blue: rgb.blue,
+            cold_white: white * (1.0 - warm_share),
+            warm_white: white * warm_share,
+        }
+    }
+
+    /// See [`Rgbww::from_rgb`]
+    #[must_use]
+    pub fn from_hsv(hsv: &Hsv, cold_kelvin: f32, warm_kelvin: f32) -> Self {
+        Self::from_rgb(&Rgb::from(*hsv), cold_kelvin, warm_kelvin)
+    }
+
+    /// Only uses the white channels to create the given color temperature.
+    ///
+    /// Cold and warm white together result in the given brightness from 0.0 to 100.0.
+    /// Color temperatures outside of the range of the white LEDs are clamped to it.
+    #[must_use]
+    pub fn from_color_temperature(
+        kelvin: f32,
+        brightness: f32,
+        cold_kelvin: f32,
+        warm_kelvin: f32,
+    ) -> Self {
+        let warm_share = warm_share(kelvin, cold_kelvin, warm_kelvin);
+        Self {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0,
+            cold_white: brightness * (1.0 - warm_share),
+            warm_white: brightness * warm_share,
+        }
+    }
+
+    /// Combines the white channels back into RGB, for example to map the state reported by a device.
+    ///
+    /// Colors brighter than representable are scaled down keeping their hue.
+    #[must_use]
+    pub fn to_rgb(self, cold_kelvin: f32, warm_kelvin: f32) -> Rgb {
+        let rgb = Rgb {
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+        };
+        let white = self.cold_white + self.warm_white;
+        let warm_share = if white > 0.0 {
+            self.warm_white / white
+        } else {
+            0.0
+        };
+        let led = mix_white_leds(cold_kelvin, warm_kelvin, warm_share);
+        add_white(&rgb, &led, white)
+    }
+
+    /// See [`Rgbww::to_rgb`]
+    #[must_use]
+    pub fn to_hsv(self, cold_kelvin: f32, warm_kelvin: f32) -> Hsv {
+        self.to_rgb(cold_kelvin, warm_kelvin).into()
+    }
+}
+
+/// Approximation of the color a white LED with the given color temperature emits.
+///
+/// The brightest channel is always 100.0.
+fn white_led_color(kelvin: f32) -> Rgb {
+    ColorTemperature::from_kelvin(kelvin).to_rgb()
+}
+
+/// Share of the warm white LED to create the given color temperature.
+/// Mixing happens in mired as that is perceived linear.
+fn warm_share(kelvin: f32, cold_kelvin: f32, warm_kelvin: f32) -> f32 {
+    let mired = 1_000_000.0 / kelvin;
+    let cold_mired = 1_000_000.0 / cold_kelvin;
+    let warm_mired = 1_000_000.0 / warm_kelvin;
+    let share = (mired - cold_mired) / (warm_mired - cold_mired);
+    if share.is_nan() {
+        0.0
+    } else {
+        share.clamp(0.0, 1.0)
+    }
+}
+
+fn mix_white_leds(cold_kelvin: f32, warm_kelvin: f32, warm_share: f32) -> Rgb {
+    Rgb::calculate_interpolated(
+        &white_led_color(cold_kelvin),
+        &white_led_color(warm_kelvin),
+        warm_share,
+    )
+}
+
+/// Removes as much of the white LED color from the RGB color as possible.
+/// Returns the remaining RGB and the amount of the white LED from 0.0 to 100.0.
+fn extract_white(rgb: &Rgb, led: &Rgb) -> (Rgb, f32) {
+    let fits = |channel: f32, led: f32| {
+        if led > 0.0 {
+            channel / led * 100.0
+        } else {
+            f32::INFINITY
+        }
+    };
+    let white = fits(rgb.red, led.red)
+        .min(fits(rgb.green, led.green))
+        .min(fits(rgb.blue, led.blue))
+        .clamp(0.0, 100.0);
+
+    let remove = |channel: f32, led: f32| (channel - (led * white / 100.0)).max(0.0);
+    let rgb = Rgb {
+        red: remove(rgb.red, led.red),
+        green: remove(rgb.green, led.green),
+        blue: remove(rgb.blue, led.blue),
+    };
+    (rgb, white)
+}
+
+fn add_white(rgb: &Rgb, led: &Rgb, white: f32) -> Rgb {
+    let add = |channel: f32, led: f32| interpolate::f32(channel, channel + led, white / 100.0);
+    let red = add(rgb.red, led.red);
+    let green = add(rgb.green, led.green);
+    let blue = add(rgb.blue, led.blue);
+
+    let max = red.max(green).max(blue);
+    let factor = if max > 100.0 { 100.0 / max } else { 1.0 };
+    Rgb {
+        red: red * factor,
+        green: green * factor,
+        blue: blue * factor,
+    }
+}
+
+#[test]
+fn neutral_white_led_is_white() {
+    let result = white_led_color(NEUTRAL_KELVIN);
+    float_eq::assert_float_eq!(result.red, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.green, 100.0, abs <= 0.1);
+    float_eq::assert_float_eq!(result.blue, 100.0, abs <= 0.1);
+}
+
+#[test]
+fn warm_white_led_is_orange() {
+    let result = white_led_color(2700.0);
+    float_eq::assert_float_eq!(result.red, 100.0, abs <= 0.1);
+    assert!(result.green < 75.0);
+    assert!(result.blue < result.green);
+}
+
+#[cfg(test)]
+mod rgbw_tests {
+    use super::*;
+
+    const WHITE: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    };
+
+    #[test]
+    fn white_is_only_white() {
+        let result = Rgbw::from_hsv(&WHITE, NEUTRAL_KELVIN);
+        float_eq::assert_float_eq!(result.red, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.white, 100.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn saturated_has_no_white() {
+        let result = Rgbw::from_hsv(&Hsv::from_hue(120.0), NEUTRAL_KELVIN);
+        float_eq::assert_float_eq!(result.red, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 100.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.white, 0.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn pastel_splits() {
+        let rgb = Rgb {
+            red: 100.0,
+            green: 50.0,
+            blue: 50.0,
+        };
+        let result = Rgbw::from_rgb(&rgb, NEUTRAL_KELVIN);
+        float_eq::assert_float_eq!(result.red, 50.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.white, 50.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn warm_led_leaves_blue() {
+        let result = Rgbw::from_hsv(&WHITE, 2700.0);
+        float_eq::assert_float_eq!(result.red, 0.0, abs <= 0.1);
+        assert!(result.blue > 0.0);
+        assert!(result.white > 0.0);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let hsv = Hsv {
+            hue: 200.0,
+            saturation: 40.0,
+            brightness: 80.0,
+        };
+        let result = Rgbw::from_hsv(&hsv, 4000.0).to_hsv(4000.0);
+        float_eq::assert_float_eq!(result.hue, 200.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.saturation, 40.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 80.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn overbright_device_state_is_scaled() {
+        let rgbw = Rgbw {
+            red: 100.0,
+            green: 100.0,
+            blue: 100.0,
+            white: 100.0,
+        };
+        let result = rgbw.to_hsv(NEUTRAL_KELVIN);
+        float_eq::assert_float_eq!(result.saturation, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 100.0, abs <= 0.1);
+    }
+}
+
+#[cfg(test)]
+mod rgbww_tests {
+    use super::*;
+
+    const WHITE: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    };
+
+    #[test]
+    fn white_uses_both_white_channels() {
+        let result = Rgbww::from_hsv(&WHITE, NEUTRAL_KELVIN, 2700.0);
+        float_eq::assert_float_eq!(result.red, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.green, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.blue, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.cold_white, 100.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.warm_white, 0.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn color_temperature_cold() {
+        let result = Rgbww::from_color_temperature(6500.0, 80.0, 6500.0, 2700.0);
+        float_eq::assert_float_eq!(result.cold_white, 80.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.warm_white, 0.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn color_temperature_warmer_than_led_is_clamped() {
+        let result = Rgbww::from_color_temperature(2000.0, 80.0, 6500.0, 2700.0);
+        float_eq::assert_float_eq!(result.cold_white, 0.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.warm_white, 80.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn color_temperature_mixes_in_mired() {
+        // 4000 K is 250 mired, halfway between 6250 K (160 mired) and 2941 K (340 mired)
+        let result = Rgbww::from_color_temperature(4000.0, 100.0, 6250.0, 2941.18);
+        float_eq::assert_float_eq!(result.cold_white, 50.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.warm_white, 50.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let hsv = Hsv {
+            hue: 30.0,
+            saturation: 60.0,
+            brightness: 50.0,
+        };
+        let result = Rgbww::from_hsv(&hsv, 5000.0, 2700.0).to_hsv(5000.0, 2700.0);
+        float_eq::assert_float_eq!(result.hue, 30.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.saturation, 60.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.1);
+    }
+}
@@ -0,0 +1,284 @@
+use core::time::Duration;
+
+use crate::light::normalize_hue;
+use crate::{Hsv, angle_distance};
+
+/// Share of the distance to the target which remains after `elapsed`
+fn remaining(half_life: Duration, elapsed: Duration) -> f32 {
+    if half_life.is_zero() {
+        return 0.0;
+    }
+    libm::exp2f(-elapsed.as_secs_f32() / half_life.as_secs_f32())
+}
+
+/// Exponential smoothing towards the target which slows down when getting closer.
+///
+/// After one `half_life` half of the distance to the target is remaining.
+/// The result is the same regardless of how often it is called.
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+#[must_use]
+pub fn exponential(current: f32, target: f32, half_life: Duration, elapsed: Duration) -> f32 {
+    (current - target) * remaining(half_life, elapsed) + target
+}
+
+/// Exponential smoothing of the hue taking the shorter way around
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+#[must_use]
+pub fn exponential_hue(current: f32, target: f32, half_life: Duration, elapsed: Duration) -> f32 {
+    let distance = angle_distance(current, target);
+    normalize_hue(target - distance * remaining(half_life, elapsed))
+}
+
+/// Exponential smoothing of all components with the same half-life
+#[must_use]
+pub fn exponential_hsv(current: &Hsv, target: &Hsv, half_life: Duration, elapsed: Duration) -> Hsv {
+    Hsv {
+        hue: exponential_hue(current.hue, target.hue, half_life, elapsed),
+        saturation: exponential(current.saturation, target.saturation, half_life, elapsed),
+        brightness: exponential(current.brightness, target.brightness, half_life, elapsed),
+    }
+}
+
+/// Critically damped spring following a target.
+///
+/// It does not overshoot when starting at rest towards a fixed target.
+/// Other than [`exponential`] it keeps its velocity when the target changes which results in smooth movements,
+/// so it can overshoot when already moving towards the target or when the target moves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub position: f32,
+    /// Units per second
+    pub velocity: f32,
+}
+
+impl Spring {
+    /// Spring resting at the position
+    #[must_use]
+    pub const fn new(position: f32) -> Self {
+        Self {
+            position,
+            velocity: 0.0,
+        }
+    }
+
+    /// Moves towards the target for the `elapsed` time and returns the new position.
+    ///
+    /// `smooth_time` is roughly the time it takes to reach the target.
+    /// Zero snaps directly to the target.
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    pub fn update(&mut self, target: f32, smooth_time: Duration, elapsed: Duration) -> f32 {
+        if smooth_time.is_zero() {
+            self.position = target;
+            self.velocity = 0.0;
+            return target;
+        }
+
+        let omega = 2.0 / smooth_time.as_secs_f32();
+        let seconds = elapsed.as_secs_f32();
+        let decay = libm::expf(-omega * seconds);
+        let offset = self.position - target;
+        let momentum = self.velocity + omega * offset;
+
+        self.position = target + (offset + momentum * seconds) * decay;
+        self.velocity = (self.velocity - omega * momentum * seconds) * decay;
+        self.position
+    }
+
+    /// Like [`Spring::update`] for hue taking the shorter way around.
+    ///
+    /// The position is kept within 0.0 to 360.0.
+    pub fn update_hue(&mut self, target: f32, smooth_time: Duration, elapsed: Duration) -> f32 {
+        let target = self.position + angle_distance(self.position, target);
+        self.update(target, smooth_time, elapsed);
+        self.position = normalize_hue(self.position);
+        self.position
+    }
+}
+
+/// [`Spring`] for each component of a [`Hsv`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HsvSpring {
+    pub hue: Spring,
+    pub saturation: Spring,
+    pub brightness: Spring,
+}
+
+impl HsvSpring {
+    /// Spring resting at the color
+    #[must_use]
+    pub const fn new(hsv: &Hsv) -> Self {
+        Self {
+            hue: Spring::new(hsv.hue),
+            saturation: Spring::new(hsv.saturation),
+            brightness: Spring::new(hsv.brightness),
+        }
+    }
+
+    #[must_use]
+    pub const fn position(&self) -> Hsv {
+        Hsv {
+            hue: self.hue.position,
+            saturation: self.saturation.position,
+            brightness: self.brightness.position,
+        }
+    }
+
+    /// Moves all components towards the target and returns the new color
+    pub fn update(&mut self, target: &Hsv, smooth_time: Duration, elapsed: Duration) -> Hsv {
+        self.hue.update_hue(target.hue, smooth_time, elapsed);
+        self.saturation
+            .update(target.saturation, smooth_time, elapsed);
+        self.brightness
+            .update(target.brightness, smooth_time, elapsed);
+        self.position()
+    }
+}
+
+#[test]
+fn exponential_half_life() {
+    let second = Duration::from_secs(1);
+    float_eq::assert_float_eq!(exponential(0.0, 100.0, second, second), 50.0, abs <= 0.01);
+    float_eq::assert_float_eq!(
+        exponential(0.0, 100.0, second, Duration::from_secs(2)),
+        75.0,
+        abs <= 0.01
+    );
+}
+
+#[test]
+fn exponential_independent_of_ticks() {
+    let half_life = Duration::from_millis(800);
+    let mut ticked = 0.0;
+    for millis in [100, 250, 50, 600] {
+        ticked = exponential(ticked, 100.0, half_life, Duration::from_millis(millis));
+    }
+    let once = exponential(0.0, 100.0, half_life, Duration::from_secs(1));
+    float_eq::assert_float_eq!(ticked, once, abs <= 0.01);
+}
+
+#[test]
+fn exponential_zero_half_life_snaps() {
+    float_eq::assert_float_eq!(
+        exponential(0.0, 100.0, Duration::ZERO, Duration::from_millis(10)),
+        100.0,
+        abs <= 0.01
+    );
+}
+
+#[test]
+fn exponential_hue_over_zero() {
+    let second = Duration::from_secs(1);
+    float_eq::assert_float_eq!(
+        exponential_hue(350.0, 30.0, second, second),
+        10.0,
+        abs <= 0.01
+    );
+}
+
+#[test]
+fn exponential_hsv_all_components() {
+    let second = Duration::from_secs(1);
+    let current = Hsv {
+        hue: 340.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    };
+    let target = Hsv {
+        hue: 20.0,
+        saturation: 100.0,
+        brightness: 0.0,
+    };
+    let result = exponential_hsv(&current, &target, second, second);
+    float_eq::assert_float_eq!(result.hue, 0.0, abs <= 0.01);
+    float_eq::assert_float_eq!(result.saturation, 50.0, abs <= 0.01);
+    float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.01);
+}
+
+#[test]
+fn spring_does_not_overshoot() {
+    let mut spring = Spring::new(0.0);
+    let smooth_time = Duration::from_millis(500);
+    let mut last = 0.0;
+    for _ in 0..200 {
+        let position = spring.update(100.0, smooth_time, Duration::from_millis(20));
+        assert!(position >= last, "{position} < {last}");
+        assert!(position <= 100.0, "{position} overshoots");
+        last = position;
+    }
+    float_eq::assert_float_eq!(last, 100.0, abs <= 0.01);
+}
+
+#[test]
+fn spring_independent_of_ticks() {
+    let smooth_time = Duration::from_millis(500);
+    let mut ticked = Spring::new(0.0);
+    for _ in 0..10 {
+        ticked.update(100.0, smooth_time, Duration::from_millis(30));
+    }
+    let mut once = Spring::new(0.0);
+    once.update(100.0, smooth_time, Duration::from_millis(300));
+    float_eq::assert_float_eq!(ticked.position, once.position, abs <= 0.01);
+    float_eq::assert_float_eq!(ticked.velocity, once.velocity, abs <= 0.01);
+}
+
+#[test]
+fn spring_keeps_velocity_on_new_target() {
+    let smooth_time = Duration::from_millis(500);
+    let mut spring = Spring::new(0.0);
+    spring.update(100.0, smooth_time, Duration::from_millis(100));
+    let velocity = spring.velocity;
+    assert!(velocity > 0.0);
+    spring.update(0.0, smooth_time, Duration::from_millis(1));
+    assert!(spring.velocity > 0.0, "velocity should not jump");
+    assert!(spring.velocity < velocity);
+}
+
+#[test]
+fn spring_zero_smooth_time_snaps() {
+    let mut spring = Spring::new(0.0);
+    float_eq::assert_float_eq!(
+        spring.update(42.0, Duration::ZERO, Duration::from_millis(10)),
+        42.0,
+        abs <= 0.01
+    );
+    float_eq::assert_float_eq!(spring.velocity, 0.0, abs <= 0.01);
+}
+
+#[test]
+fn spring_hue_over_zero() {
+    let mut spring = Spring::new(350.0);
+    let smooth_time = Duration::from_millis(500);
+    for _ in 0..10 {
+        let hue = spring.update_hue(30.0, smooth_time, Duration::from_millis(20));
+        assert!((0.0..360.0).contains(&hue));
+        assert!(
+            angle_distance(350.0, hue) >= 0.0,
+            "{hue} took the long way around"
+        );
+    }
+    for _ in 0..200 {
+        spring.update_hue(30.0, smooth_time, Duration::from_millis(20));
+    }
+    float_eq::assert_float_eq!(spring.position, 30.0, abs <= 0.01);
+}
+
+#[test]
+fn hsv_spring_reaches_target() {
+    let mut spring = HsvSpring::new(&Hsv::from_hue(300.0));
+    let target = Hsv {
+        hue: 60.0,
+        saturation: 20.0,
+        brightness: 10.0,
+    };
+    for _ in 0..200 {
+        spring.update(
+            &target,
+            Duration::from_millis(500),
+            Duration::from_millis(20),
+        );
+    }
+    let result = spring.position();
+    float_eq::assert_float_eq!(result.hue, 60.0, abs <= 0.01);
+    float_eq::assert_float_eq!(result.saturation, 20.0, abs <= 0.01);
+    float_eq::assert_float_eq!(result.brightness, 10.0, abs <= 0.01);
+}
@@ -0,0 +1,140 @@
+use core::time::Duration;
+
+use crate::{Easing, Hsv};
+
+/// Fade from one color to another over time.
+///
+/// Instants are the time since any fixed point like the boot of the device or the UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    pub start: Hsv,
+    pub end: Hsv,
+    pub start_time: Duration,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Position from 0.0 to 1.0 at the given instant before easing
+    #[must_use]
+    pub fn position_at(&self, now: Duration) -> f32 {
+        let Some(elapsed) = now.checked_sub(self.start_time) else {
+            return 0.0;
+        };
+        if elapsed >= self.duration {
+            return 1.0;
+        }
+        elapsed.as_secs_f32() / self.duration.as_secs_f32()
+    }
+
+    #[must_use]
+    pub fn value_at(&self, now: Duration) -> Hsv {
+        let position = self.easing.apply(self.position_at(now));
+        Hsv::calculate_interpolated(&self.start, &self.end, position)
+    }
+
+    #[must_use]
+    pub fn is_finished(&self, now: Duration) -> bool {
+        now.saturating_sub(self.start_time) >= self.duration
+    }
+
+    /// Continues from the current value towards a new target.
+    ///
+    /// The value at `now` stays the same so there is no visible jump.
+    pub fn retarget(&mut self, target: Hsv, now: Duration, duration: Duration) {
+        self.start = self.value_at(now);
+        self.end = target;
+        self.start_time = now;
+        self.duration = duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Hsv = Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 0.0,
+    };
+    const RED: Hsv = Hsv::from_hue(0.0);
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    fn linear_fade() -> Transition {
+        Transition {
+            start: BLACK,
+            end: RED,
+            start_time: secs(10),
+            duration: secs(4),
+            easing: Easing::Linear,
+        }
+    }
+
+    #[test]
+    fn before_start() {
+        let transition = linear_fade();
+        float_eq::assert_float_eq!(transition.value_at(secs(5)).brightness, 0.0, abs <= 0.1);
+        assert!(!transition.is_finished(secs(5)));
+    }
+
+    #[test]
+    fn halfway() {
+        let transition = linear_fade();
+        let result = transition.value_at(secs(12));
+        float_eq::assert_float_eq!(result.saturation, 50.0, abs <= 0.1);
+        float_eq::assert_float_eq!(result.brightness, 50.0, abs <= 0.1);
+        assert!(!transition.is_finished(secs(12)));
+    }
+
+    #[test]
+    fn finished() {
+        let transition = linear_fade();
+        float_eq::assert_float_eq!(transition.value_at(secs(14)).brightness, 100.0, abs <= 0.1);
+        float_eq::assert_float_eq!(transition.value_at(secs(60)).brightness, 100.0, abs <= 0.1);
+        assert!(transition.is_finished(secs(14)));
+    }
+
+    #[test]
+    fn zero_duration_is_instant() {
+        let transition = Transition {
+            duration: Duration::ZERO,
+            ..linear_fade()
+        };
+        float_eq::assert_float_eq!(transition.value_at(secs(10)).brightness, 100.0, abs <= 0.1);
+        assert!(transition.is_finished(secs(10)));
+    }
+
+    #[test]
+    fn eased() {
+        let transition = Transition {
+            easing: Easing::QuadIn,
+            ..linear_fade()
+        };
+        float_eq::assert_float_eq!(transition.value_at(secs(12)).brightness, 25.0, abs <= 0.1);
+    }
+
+    #[test]
+    fn retarget_has_no_jump() {
+        let mut transition = linear_fade();
+        let now = Duration::from_millis(11_500);
+        let before = transition.value_at(now);
+        transition.retarget(BLACK, now, secs(2));
+        let after = transition.value_at(now);
+        float_eq::assert_float_eq!(after.brightness, before.brightness, abs <= 0.001);
+        float_eq::assert_float_eq!(after.saturation, before.saturation, abs <= 0.001);
+        assert!(!transition.is_finished(now));
+
+        float_eq::assert_float_eq!(
+            transition
+                .value_at(Duration::from_millis(13_500))
+                .brightness,
+            0.0,
+            abs <= 0.1
+        );
+        assert!(transition.is_finished(Duration::from_millis(13_500)));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::rgb::{linear_to_srgb, srgb_to_linear};
+use crate::{Hsv, Rgb};
+
+/// CIE 1931 xy chromaticity as used by Zigbee and Hue lights
+#[derive(Clone, Copy)]
+pub struct Xy {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Triangle of the colors a lamp can produce
+#[derive(Clone, Copy)]
+pub struct Gamut {
+    pub red: Xy,
+    pub green: Xy,
+    pub blue: Xy,
+}
+
+impl Xy {
+    /// White point of sRGB
+    pub const D65: Self = Self {
+        x: 0.3127,
+        y: 0.3290,
+    };
+
+    /// Converts from sRGB. Black has no chromaticity and results in [`Xy::D65`].
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn from_rgb(rgb: &Rgb) -> Self {
+        let red = srgb_to_linear(rgb.red / 100.0);
+        let green = srgb_to_linear(rgb.green / 100.0);
+        let blue = srgb_to_linear(rgb.blue / 100.0);
+
+        let x = 0.4124 * red + 0.3576 * green + 0.1805 * blue;
+        let y = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+        let z = 0.0193 * red + 0.1192 * green + 0.9505 * blue;
+
+        let sum = x + y + z;
+        if sum <= 0.0 {
+            return Self::D65;
+        }
+        Self {
+            x: x / sum,
+            y: y / sum,
+        }
+    }
+
+    /// Converts the hue and saturation. The brightness is ignored as xy has none.
+    #[must_use]
+    pub fn from_hsv(hsv: &Hsv) -> Self {
+        Self::from_rgb(&Rgb::from(Hsv {
+            brightness: 100.0,
+            ..*hsv
+        }))
+    }
+
+    /// Converts to sRGB with the given brightness from 0.0 to 100.0.
+    ///
+    /// Colors outside of sRGB are clamped to the nearest sRGB color.
+    #[must_use]
+    pub fn to_rgb(self, brightness: f32) -> Rgb {
+        self.to_hsv(brightness).into()
+    }
+
+    /// See [`Xy::to_rgb`]
+    #[expect(clippy::suboptimal_flops, reason = "requires std")]
+    #[must_use]
+    pub fn to_hsv(self, brightness: f32) -> Hsv {
+        if self.y <= 0.0 {
+            return Hsv {
+                hue: 0.0,
+                saturation: 0.0,
+                brightness,
+            };
+        }
+
+        let x = self.x / self.y;
+        let z = (1.0 - self.x - self.y) / self.y;
+        let red = (3.2406 * x - 1.5372 - 0.4986 * z).max(0.0);
+        let green = (-0.9689 * x + 1.8758 + 0.0415 * z).max(0.0);
+        let blue = (0.0557 * x - 0.2040 + 1.0570 * z).max(0.0);
+
+        let max = red.max(green).max(blue);
+        if max <= 0.0 {
+            return Hsv {
+                hue: 0.0,
+                saturation: 0.0,
+                brightness,
+            };
+        }
+        let hsv = Hsv::from_rgb_f32(
+            linear_to_srgb(red / max),
+            linear_to_srgb(green / max),
+            linear_to_srgb(blue / max),
+        );
+        Hsv { brightness, ..hsv }
+    }
+}
+
+impl Gamut {
+    /// Older Hue lamps like Bloom and Iris
+    pub const A: Self = Self {
+        red: Xy { x: 0.704, y: 0.296 },
+        green: Xy {
+            x: 0.2151,
+            y: 0.7106,
+        },
+        blue: Xy { x: 0.138, y: 0.08 },
+    };
+    /// First generation Hue bulbs
+    pub const B: Self = Self {
+        red: Xy { x: 0.675, y: 0.322 },
+        green: Xy { x: 0.409, y: 0.518 },
+        blue: Xy { x: 0.167, y: 0.04 },
+    };
+    /// Current Hue bulbs and light strips
+    pub const C: Self = Self {
+        red: Xy {
+            x: 0.6915,
+            y: 0.3083,
+        },
+        green: Xy { x: 0.17, y: 0.7 },
+        blue: Xy {
+            x: 0.1532,
+            y: 0.0475,
+        },
+    };
+    pub const SRGB: Self = Self {
+        red: Xy { x: 0.64, y: 0.33 },
+        green: Xy { x: 0.3, y: 0.6 },
+        blue: Xy { x: 0.15, y: 0.06 },
+    };
+
+    #[must_use]
+    pub fn contains(&self, xy: Xy) -> bool {
+        let red_green = cross(self.red, self.green, xy);
+        let green_blue = cross(self.green, self.blue, xy);
+        let blue_red = cross(self.blue, self.red, xy);
+        let has_negative = red_green < 0.0 || green_blue < 0.0 || blue_red < 0.0;
+        let has_positive = red_green > 0.0 || green_blue > 0.0 || blue_red > 0.0;
+        !(has_negative && has_positive)
+    }
+
+    /// Returns the closest point inside the gamut
+    #[must_use]
+    pub fn clamp(&self, xy: Xy) -> Xy {
+        if self.contains(xy) {
+            return xy;
+        }
+        let candidates = [
+            closest_on_line(self.red, self.green, xy),
+            closest_on_line(self.green, self.blue, xy),
+            closest_on_line(self.blue, self.red, xy),
+        ];
+        let mut closest = candidates[0];
+        for candidate in candidates {
+            if distance_squared(candidate, xy) < distance_squared(closest, xy) {
+                closest = candidate;
+            }
+        }
+        closest
+    }
+}
+
+/// Which side of the line from `start` to `end` the point is on
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+fn cross(start: Xy, end: Xy, point: Xy) -> f32 {
+    (end.x - start.x) * (point.y - start.y) - (end.y - start.y) * (point.x - start.x)
+}
+
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+fn closest_on_line(start: Xy, end: Xy, point: Xy) -> Xy {
+    let line_x = end.x - start.x;
+    let line_y = end.y - start.y;
+    let length_squared = line_x * line_x + line_y * line_y;
+    let position = (((point.x - start.x) * line_x + (point.y - start.y) * line_y) / length_squared)
+        .clamp(0.0, 1.0);
+    Xy {
+        x: start.x + line_x * position,
+        y: start.y + line_y * position,
+    }
+}
+
+#[expect(clippy::suboptimal_flops, reason = "requires std")]
+fn distance_squared(start: Xy, end: Xy) -> f32 {
+    let x = end.x - start.x;
+    let y = end.y - start.y;
+    x * x + y * y
+}
+
+#[test]
+fn white_is_d65() {
+    let result = Xy::from_hsv(&Hsv {
+        hue: 0.0,
+        saturation: 0.0,
+        brightness: 100.0,
+    });
+    float_eq::assert_float_eq!(result.x, 0.3127, abs <= 0.001);
+    float_eq::assert_float_eq!(result.y, 0.3290, abs <= 0.001);
+}
+
+#[test]
+fn black_is_d65() {
+    let result = Xy::from_rgb(&Rgb {
+        red: 0.0,
+        green: 0.0,
+        blue: 0.0,
+    });
+    float_eq::assert_float_eq!(result.x, 0.3127, abs <= 0.001);
+    float_eq::assert_float_eq!(result.y, 0.3290, abs <= 0.001);
+}
+
+#[test]
+fn red_is_srgb_primary() {
+    let result = Xy::from_hsv(&Hsv::from_hue(0.0));
+    float_eq::assert_float_eq!(result.x, 0.64, abs <= 0.001);
+    float_eq::assert_float_eq!(result.y, 0.33, abs <= 0.001);
+}
+
+#[test]
+fn brightness_is_ignored() {
+    let result = Xy::from_hsv(&Hsv {
+        hue: 120.0,
+        saturation: 100.0,
+        brightness: 1.0,
+    });
+    float_eq::assert_float_eq!(result.x, 0.30, abs <= 0.001);
+    float_eq::assert_float_eq!(result.y, 0.60, abs <= 0.001);
+}
+
+#[test]
+fn hsv_roundtrip() {
+    for hue in [0.0, 60.0, 120.0, 200.0, 300.0] {
+        let hsv = Hsv {
+            hue,
+            saturation: 70.0,
+            brightness: 40.0,
+        };
+        let result = Xy::from_hsv(&hsv).to_hsv(40.0);
+        float_eq::assert_float_eq!(result.hue, hue, abs <= 0.5);
+        float_eq::assert_float_eq!(result.saturation, 70.0, abs <= 0.5);
+        float_eq::assert_float_eq!(result.brightness, 40.0, abs <= 0.1);
+    }
+}
+
+#[test]
+fn outside_srgb_is_clamped() {
+    // Pure spectral green of Gamut C is more saturated than sRGB green
+    let result = Gamut::C.green.to_hsv(100.0);
+    float_eq::assert_float_eq!(result.hue, 120.0, abs <= 10.0);
+    float_eq::assert_float_eq!(result.saturation, 100.0, abs <= 0.1);
+}
+
+#[test]
+fn gamut_contains_white() {
+    assert!(Gamut::A.contains(Xy::D65));
+    assert!(Gamut::C.contains(Xy::D65));
+}
+
+#[test]
+fn gamut_b_barely_misses_white() {
+    assert!(!Gamut::B.contains(Xy::D65));
+    let result = Gamut::B.clamp(Xy::D65);
+    float_eq::assert_float_eq!(result.x, 0.3127, abs <= 0.001);
+    float_eq::assert_float_eq!(result.y, 0.3290, abs <= 0.001);
+}
+
+#[test]
+fn gamut_contains_corner() {
+    assert!(Gamut::B.contains(Gamut::B.red));
+}
+
+#[test]
+fn gamut_b_does_not_contain_srgb_green() {
+    assert!(!Gamut::B.contains(Gamut::SRGB.green));
+}
+
+#[test]
+fn clamp_inside_is_unchanged() {
+    let result = Gamut::C.clamp(Xy::D65);
+    float_eq::assert_float_eq!(result.x, 0.3127, abs <= 0.0001);
+    float_eq::assert_float_eq!(result.y, 0.3290, abs <= 0.0001);
+}
+
+#[test]
+fn clamp_to_edge() {
+    // Below the blue to red edge of gamut B
+    let result = Gamut::B.clamp(Xy { x: 0.4, y: 0.1 });
+    float_eq::assert_float_eq!(
+        cross(Gamut::B.blue, Gamut::B.red, result),
+        0.0,
+        abs <= 0.0001
+    );
+    assert!(result.y > 0.1);
+}
+
+#[test]
+fn clamp_to_corner() {
+    let result = Gamut::B.clamp(Xy { x: 0.8, y: 0.3 });
+    float_eq::assert_float_eq!(result.x, 0.675, abs <= 0.0001);
+    float_eq::assert_float_eq!(result.y, 0.322, abs <= 0.0001);
+}